@@ -1,5 +1,6 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
+use serde::Deserialize;
 use std::path::PathBuf;
 
 #[derive(Debug, Parser, Clone, PartialEq)]
@@ -21,6 +22,10 @@ pub struct CliDerive {
 pub enum SubCommands {
     /// Generate shell completions
     Completions(CommandCompletions),
+    /// Print a unified diff of all pending changes instead of writing them
+    /// "--file-mode" and "--check" are ignored
+    #[command(verbatim_doc_comment)]
+    Diff(Box<MainOptions>),
 }
 
 #[derive(Debug, Parser, Clone, PartialEq)]
@@ -35,86 +40,252 @@ pub struct CommandCompletions {
     pub output_file_path: Option<PathBuf>,
 }
 
-#[derive(Debug, Parser, Clone, PartialEq)]
+#[derive(Debug, Parser, Clone, PartialEq, Default)]
 pub struct MainOptions {
-    /// Input diesel schema file
-    #[arg(short = 'i', long = "input")]
-    pub input: PathBuf,
+    /// Config file to use, default "dsync.toml" in the current directory if it exists
+    /// Options given on the cli take precedence over the config file
+    #[arg(long = "config", verbatim_doc_comment)]
+    pub config: Option<PathBuf>,
+
+    /// Input diesel schema file, required if not set in the config file
+    /// Can also be a diesel "migrations" directory or a ".sql" file
+    /// or a sqlite database file, if compiled with feature "sqlite"
+    #[arg(short = 'i', long = "input", verbatim_doc_comment)]
+    pub input: Option<PathBuf>,
 
-    /// Output file, stdout if not present
+    /// Output directory, required if not set in the config file
     #[arg(short = 'o', long = "output")]
-    pub output: PathBuf,
+    pub output: Option<PathBuf>,
 
     /// adds the #[tsync] attribute to all structs; see https://github.com/Wulf/tsync
-    #[arg(long = "tsync")]
+    #[arg(
+        long = "tsync",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
     #[cfg(feature = "tsync")]
-    pub tsync: bool,
+    pub tsync: Option<bool>,
 
     /// uses diesel_async for generated functions; see https://github.com/weiznich/diesel_async
-    #[arg(long = "async")]
+    #[arg(
+        long = "async",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
     #[cfg(feature = "async")]
-    pub use_async: bool,
+    pub use_async: Option<bool>,
 
     /// List of columns which are automatically generated but are not primary keys (for example: "created_at", "updated_at", etc.)
     #[arg(short = 'g', long = "autogenerated-columns")]
     pub autogenerated_columns: Option<Vec<String>>,
 
+    /// Infer autogenerated columns from the schema, like serial types and sqlite rowid aliases
+    #[arg(
+        long = "infer-autogenerated",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub infer_autogenerated: Option<bool>,
+
+    /// Glob pattern for columns to infer as autogenerated, like "*_at", requires "--infer-autogenerated"
+    #[arg(long = "autogenerated-patterns")]
+    pub autogenerated_patterns: Option<Vec<String>>,
+
     /// rust type which describes a connection, for example: "diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>"
-    #[arg(short = 'c', long = "connection-type")]
-    pub connection_type: String,
+    /// Required if not set in the config file
+    #[arg(short = 'c', long = "connection-type", verbatim_doc_comment)]
+    pub connection_type: Option<String>,
 
     /// Disable generating serde implementations
-    #[arg(long = "no-serde")]
-    pub no_serde: bool,
+    #[arg(
+        long = "no-serde",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub no_serde: Option<bool>,
 
     /// Set custom schema use path, Default "crate::schema::"
-    #[arg(long = "schema-path", default_value = "crate::schema::")]
-    pub schema_path: String,
+    #[arg(long = "schema-path")]
+    pub schema_path: Option<String>,
 
     /// Set custom model use path, Default "crate::models::"
-    #[arg(long = "model-path", default_value = "crate::models::")]
-    pub model_path: String,
+    #[arg(long = "model-path")]
+    pub model_path: Option<String>,
 
     /// Only generate the necessary diesel derives for a struct
-    #[arg(long = "only-necessary-derives")]
-    pub only_necessary_derives: bool,
+    #[arg(
+        long = "only-necessary-derives",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub only_necessary_derives: Option<bool>,
 
     /// Only generate common structs once and put them in a common file
-    #[arg(long = "once-common-structs")]
-    pub once_common_structs: bool,
+    #[arg(
+        long = "once-common-structs",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub once_common_structs: Option<bool>,
 
     /// Only generate a single model file instead of a folder with a "mod.rs" and a "generated.rs"
-    #[arg(long = "single-model-file")]
-    pub single_model_file: bool,
+    #[arg(
+        long = "single-model-file",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub single_model_file: Option<bool>,
 
-    /// Set which file mode to use
+    /// Set which file mode to use, Default "overwrite"
     /// "mod.rs" file will still be updated if necessary
-    #[arg(long = "file-mode", value_enum, verbatim_doc_comment, default_value_t=FileMode::Overwrite)]
-    pub file_mode: FileMode,
+    #[arg(long = "file-mode", value_enum, verbatim_doc_comment)]
+    pub file_mode: Option<FileMode>,
 
     /// A prefix to treat a table as read-only, like "view_"
     #[arg(long = "read-only-prefix")]
     pub read_only_prefix: Option<Vec<String>>,
 
     /// Dont generate any "impl" and only generate the model structs themself
-    #[arg(long = "no-impls")]
-    pub no_impls: bool,
+    #[arg(
+        long = "no-impls",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub no_impls: Option<bool>,
 
     /// Only generate the type "Connection" once and put it in "common.rs"
-    #[arg(long = "once-connection")]
-    pub once_connection: bool,
+    #[arg(
+        long = "once-connection",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub once_connection: Option<bool>,
 
     /// Lessen Conflicts with diesel types
-    #[arg(long = "lessen-conflicts")]
-    pub lessen_conflicts: bool,
+    #[arg(
+        long = "lessen-conflicts",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub lessen_conflicts: Option<bool>,
 
     /// Use "str" over "String" for "Create*" structs
-    #[arg(long = "create-str")]
-    pub create_str: bool,
+    #[arg(
+        long = "create-str",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub create_str: Option<bool>,
+
+    /// Set how "Update*" structs set columns, Default "patch"
+    #[arg(long = "update-strategy", value_enum)]
+    pub update_strategy: Option<UpdateStrategy>,
+
+    /// Use borrowed types for "Create*" and "Update*" structs, like "&'a str" for "String"
+    #[arg(long = "borrowed-types", value_enum)]
+    pub borrowed_types: Option<BorrowedTypes>,
+
+    /// Generate a "*Filter" struct with a field per column and a "filter" function to paginate through the matching rows
+    #[arg(
+        long = "filter-struct",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub filter_struct: Option<bool>,
+
+    /// Variants of a custom sql type to generate a rust enum for, like "mood=happy,sad,ok"
+    /// The name can either be the name in the database or the rust name of the sql type
+    #[arg(long = "sql-type-variants", verbatim_doc_comment)]
+    pub sql_type_variants: Option<Vec<String>>,
+
+    /// Rust type to use for a sql type instead of the built-in mapping, like "Numeric=rust_decimal::Decimal"
+    #[arg(long = "sql-type-override")]
+    pub sql_type_overrides: Option<Vec<String>>,
+
+    /// Rust type to use for a specific column, like "users.settings=crate::types::UserSettings"
+    /// Nullable columns are still wrapped in a "Option"
+    #[arg(long = "column-type-override", verbatim_doc_comment)]
+    pub column_type_overrides: Option<Vec<String>>,
+
+    /// Set which library to use for date and time types, Default "chrono"
+    #[arg(long = "datetime-backend", value_enum)]
+    pub datetime_backend: Option<DateTimeBackend>,
+
+    /// Set which library to use for postgres "Inet" and "Cidr" types, Default "ipnetwork"
+    #[arg(long = "ip-network-backend", value_enum)]
+    pub ip_network_backend: Option<IpNetworkBackend>,
+
+    /// Set which database the schema is for, used to infer backend specific autogenerated columns
+    /// like sqlite rowid aliases with "--infer-autogenerated"
+    #[arg(long = "backend", value_enum, verbatim_doc_comment)]
+    pub backend: Option<DatabaseBackend>,
+
+    /// Generate a "Range<T>" type into "common.rs" and use it for postgres range types
+    /// instead of a tuple of "Bound"
+    #[arg(
+        long = "range-type",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        verbatim_doc_comment
+    )]
+    pub range_type: Option<bool>,
+
+    /// Singularize table names for struct names, like "users" to "User"
+    #[arg(
+        long = "singularize",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub singularize: Option<bool>,
+
+    /// Singular for a irregular plural table name or last word of a table name, like "criteria=criterion"
+    #[arg(long = "singular-override")]
+    pub singular_overrides: Option<Vec<String>>,
+
+    /// Template for the name of the main struct, "{name}" is replaced with the struct name of the table
+    /// Default "{name}"
+    #[arg(long = "read-struct-name", verbatim_doc_comment)]
+    pub read_struct_name: Option<String>,
+
+    /// Template for the name of the "Create*" struct, like "New{name}", Default "Create{name}"
+    #[arg(long = "create-struct-name")]
+    pub create_struct_name: Option<String>,
+
+    /// Template for the name of the "Update*" struct, like "{name}Changeset", Default "Update{name}"
+    #[arg(long = "update-struct-name")]
+    pub update_struct_name: Option<String>,
+
+    /// Do not write anything, only report which files are out of date
+    /// Exits with an error if any file would be changed, ignores "--file-mode"
+    #[arg(long = "check", verbatim_doc_comment)]
+    pub check: bool,
+
+    /// Exit with an error if there are any warnings during generation
+    #[arg(long = "deny-warnings")]
+    pub deny_warnings: bool,
+
+    /// Print additional information, like which columns are inferred as autogenerated
+    #[arg(short = 'v', long = "verbose")]
+    pub verbose: bool,
 }
 
-#[derive(ValueEnum, Clone, Debug, PartialEq, Copy)]
+#[derive(ValueEnum, Clone, Debug, PartialEq, Copy, Deserialize)]
 #[value(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum FileMode {
     /// Overwrite the file path, as long as a dsync signature is present
     Overwrite,
@@ -134,3 +305,106 @@ impl From<FileMode> for dsync_hasezoey::FileMode {
         }
     }
 }
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Copy, Deserialize)]
+#[value(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DateTimeBackend {
+    /// Use the "chrono" crate
+    Chrono,
+    /// Use the "time" crate
+    Time,
+}
+
+impl From<DateTimeBackend> for dsync_hasezoey::DateTimeBackend {
+    fn from(value: DateTimeBackend) -> Self {
+        use dsync_hasezoey::DateTimeBackend as libDateTimeBackend;
+        match value {
+            DateTimeBackend::Chrono => libDateTimeBackend::Chrono,
+            DateTimeBackend::Time => libDateTimeBackend::Time,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Copy, Deserialize)]
+#[value(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    /// Schema is for postgres
+    Postgres,
+    /// Schema is for mysql
+    Mysql,
+    /// Schema is for sqlite
+    Sqlite,
+}
+
+impl From<DatabaseBackend> for dsync_hasezoey::DatabaseBackend {
+    fn from(value: DatabaseBackend) -> Self {
+        use dsync_hasezoey::DatabaseBackend as libDatabaseBackend;
+        match value {
+            DatabaseBackend::Postgres => libDatabaseBackend::Postgres,
+            DatabaseBackend::Mysql => libDatabaseBackend::Mysql,
+            DatabaseBackend::Sqlite => libDatabaseBackend::Sqlite,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Copy, Deserialize)]
+#[value(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum IpNetworkBackend {
+    /// Use the "ipnetwork" crate
+    IpNetwork,
+    /// Use the "ipnet" crate
+    IpNet,
+}
+
+impl From<IpNetworkBackend> for dsync_hasezoey::IpNetworkBackend {
+    fn from(value: IpNetworkBackend) -> Self {
+        use dsync_hasezoey::IpNetworkBackend as libIpNetworkBackend;
+        match value {
+            IpNetworkBackend::IpNetwork => libIpNetworkBackend::IpNetwork,
+            IpNetworkBackend::IpNet => libIpNetworkBackend::IpNet,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Copy, Deserialize)]
+#[value(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateStrategy {
+    /// Wrap all fields in a "Option", where "Some(None)" sets a nullable column to "NULL"
+    Patch,
+    /// Set all columns, where "None" sets a nullable column to "NULL"
+    Replace,
+}
+
+impl From<UpdateStrategy> for dsync_hasezoey::UpdateStrategy {
+    fn from(value: UpdateStrategy) -> Self {
+        use dsync_hasezoey::UpdateStrategy as libUpdateStrategy;
+        match value {
+            UpdateStrategy::Patch => libUpdateStrategy::Patch,
+            UpdateStrategy::Replace => libUpdateStrategy::Replace,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Copy, Deserialize)]
+#[value(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum BorrowedTypes {
+    /// Use references, like "&'a str", "&'a [u8]" and "&'a [T]"
+    Ref,
+    /// Use "Cow", like "Cow<'a, str>", "Cow<'a, [u8]>" and "Cow<'a, [T]>"
+    Cow,
+}
+
+impl From<BorrowedTypes> for dsync_hasezoey::BorrowedTypes {
+    fn from(value: BorrowedTypes) -> Self {
+        use dsync_hasezoey::BorrowedTypes as libBorrowedTypes;
+        match value {
+            BorrowedTypes::Ref => libBorrowedTypes::Ref,
+            BorrowedTypes::Cow => libBorrowedTypes::Cow,
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use anyhow::{Context, Result};
+use dsync_hasezoey::TableOptions;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::clap_conf::{
+    BorrowedTypes, DatabaseBackend, DateTimeBackend, FileMode, IpNetworkBackend, UpdateStrategy,
+};
+
+/// Name of the config file that is used if no "--config" is given
+pub const CONFIG_FILE_NAME: &str = "dsync.toml";
+
+/// Options from a "dsync.toml" config file, options set via the cli take precedence
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Input diesel schema file, relative to the config file
+    pub input: Option<PathBuf>,
+    /// Output directory, relative to the config file
+    pub output: Option<PathBuf>,
+    pub connection_type: Option<String>,
+    pub schema_path: Option<String>,
+    pub model_path: Option<String>,
+    pub once_common_structs: Option<bool>,
+    pub single_model_file: Option<bool>,
+    pub file_mode: Option<FileMode>,
+    pub read_only_prefix: Option<Vec<String>>,
+    pub once_connection: Option<bool>,
+    pub lessen_conflicts: Option<bool>,
+    pub datetime_backend: Option<DateTimeBackend>,
+    pub ip_network_backend: Option<IpNetworkBackend>,
+    pub backend: Option<DatabaseBackend>,
+    pub range_type: Option<bool>,
+    pub singularize: Option<bool>,
+    pub read_struct_name: Option<String>,
+    pub create_struct_name: Option<String>,
+    pub update_struct_name: Option<String>,
+    #[serde(default)]
+    pub singular_overrides: HashMap<String, String>,
+    #[serde(default)]
+    pub sql_type_variants: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub sql_type_overrides: HashMap<String, String>,
+    #[serde(default)]
+    pub column_type_overrides: HashMap<String, String>,
+    /// Options for all tables
+    #[serde(default)]
+    pub defaults: TableConfig,
+    /// Options for specific tables, the key can also be a glob pattern like "view_*"
+    #[serde(default)]
+    pub tables: HashMap<String, TableConfig>,
+}
+
+/// Options for a table, mapping onto [TableOptions]
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TableConfig {
+    pub ignore: Option<bool>,
+    pub autogenerated_columns: Option<Vec<String>>,
+    pub infer_autogenerated: Option<bool>,
+    pub autogenerated_patterns: Option<Vec<String>>,
+    pub tsync: Option<bool>,
+    #[serde(rename = "async")]
+    pub use_async: Option<bool>,
+    pub serde: Option<bool>,
+    pub only_necessary_derives: Option<bool>,
+    pub read_only: Option<bool>,
+    pub impls: Option<bool>,
+    pub create_str: Option<bool>,
+    pub exclude_columns: Option<Vec<String>>,
+    pub create_exclude: Option<Vec<String>>,
+    pub update_exclude: Option<Vec<String>>,
+    pub read_only_columns: Option<Vec<String>>,
+    pub update_strategy: Option<UpdateStrategy>,
+    pub borrowed_types: Option<BorrowedTypes>,
+    pub struct_name: Option<String>,
+    pub unique_columns: Option<Vec<String>>,
+    pub indexed_columns: Option<Vec<String>>,
+    pub filter_struct: Option<bool>,
+}
+
+impl ConfigFile {
+    /// Load the config file from `path` or, if not given, from "dsync.toml" in the current directory if it exists
+    pub fn load(path: Option<&Path>) -> Result<Option<Self>> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => {
+                let path = PathBuf::from(CONFIG_FILE_NAME);
+
+                if !path.exists() {
+                    return Ok(None);
+                }
+
+                path
+            }
+        };
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {path:?}"))?;
+        let mut config: Self = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file {path:?}"))?;
+
+        // paths in the config file are relative to the config file itself
+        if let Some(base) = path.parent() {
+            config.input = config.input.map(|input| base.join(input));
+            config.output = config.output.map(|output| base.join(output));
+        }
+
+        Ok(Some(config))
+    }
+}
+
+impl TableConfig {
+    /// Convert to [TableOptions], only setting options that are set in the config
+    pub fn to_table_options(&self) -> Result<TableOptions<'_>> {
+        let mut options = TableOptions::default();
+
+        if let Some(ignore) = self.ignore {
+            options.set_ignore(ignore);
+        }
+
+        if let Some(ref autogenerated_columns) = self.autogenerated_columns {
+            options.set_autogenerated_columns(
+                autogenerated_columns.iter().map(String::as_str).collect(),
+            );
+        }
+
+        if let Some(infer_autogenerated) = self.infer_autogenerated {
+            options.set_infer_autogenerated(infer_autogenerated);
+        }
+
+        if let Some(ref autogenerated_patterns) = self.autogenerated_patterns {
+            options.set_autogenerated_patterns(
+                autogenerated_patterns.iter().map(String::as_str).collect(),
+            );
+        }
+
+        if let Some(tsync) = self.tsync {
+            #[cfg(feature = "tsync")]
+            options.set_tsync(tsync);
+
+            #[cfg(not(feature = "tsync"))]
+            anyhow::ensure!(
+                !tsync,
+                "Option \"tsync\" is set, but feature \"tsync\" is not enabled"
+            );
+        }
+
+        if let Some(use_async) = self.use_async {
+            #[cfg(feature = "async")]
+            options.set_async(use_async);
+
+            #[cfg(not(feature = "async"))]
+            anyhow::ensure!(
+                !use_async,
+                "Option \"async\" is set, but feature \"async\" is not enabled"
+            );
+        }
+
+        if let Some(serde) = self.serde {
+            options.set_serde(serde);
+        }
+
+        if let Some(only_necessary_derives) = self.only_necessary_derives {
+            options.set_only_necessary_derives(only_necessary_derives);
+        }
+
+        if let Some(read_only) = self.read_only {
+            options.set_read_only(read_only);
+        }
+
+        if let Some(impls) = self.impls {
+            options.set_generate_impls(impls);
+        }
+
+        if let Some(create_str) = self.create_str {
+            options.set_create_str(create_str);
+        }
+
+        if let Some(ref exclude_columns) = self.exclude_columns {
+            options.set_exclude_columns(exclude_columns.iter().map(String::as_str).collect());
+        }
+
+        if let Some(ref create_exclude) = self.create_exclude {
+            options.set_create_exclude(create_exclude.iter().map(String::as_str).collect());
+        }
+
+        if let Some(ref update_exclude) = self.update_exclude {
+            options.set_update_exclude(update_exclude.iter().map(String::as_str).collect());
+        }
+
+        if let Some(ref read_only_columns) = self.read_only_columns {
+            options.set_read_only_columns(read_only_columns.iter().map(String::as_str).collect());
+        }
+
+        if let Some(update_strategy) = self.update_strategy {
+            options.set_update_strategy(update_strategy.into());
+        }
+
+        if let Some(borrowed_types) = self.borrowed_types {
+            options.set_borrowed_types(borrowed_types.into());
+        }
+
+        if let Some(ref struct_name) = self.struct_name {
+            options.set_struct_name(struct_name);
+        }
+
+        if let Some(ref unique_columns) = self.unique_columns {
+            options.set_unique_columns(unique_columns.iter().map(String::as_str).collect());
+        }
+
+        if let Some(ref indexed_columns) = self.indexed_columns {
+            options.set_indexed_columns(indexed_columns.iter().map(String::as_str).collect());
+        }
+
+        if let Some(filter_struct) = self.filter_struct {
+            options.set_filter_struct(filter_struct);
+        }
+
+        Ok(options)
+    }
+}
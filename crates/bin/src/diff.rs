@@ -0,0 +1,28 @@
+use anyhow::Result;
+use dsync_hasezoey::FileMode;
+
+use crate::clap_conf::MainOptions;
+
+/// Handler function for the "diff" subcommand
+/// This function is mainly to keep the code structured and sorted
+#[inline]
+pub fn command_diff(sub_args: MainOptions) -> Result<()> {
+    let deny_warnings = sub_args.deny_warnings;
+    let verbose = sub_args.verbose;
+
+    let result = crate::generate(sub_args, Some(FileMode::Check))?;
+
+    if verbose {
+        crate::print_autogenerated_columns(&result);
+    }
+
+    for change in result.changed_files() {
+        if let Some(diff) = &change.diff {
+            print!("{}", diff.unified_diff(&change.file));
+        }
+    }
+
+    crate::print_warnings(&result.warnings, deny_warnings)?;
+
+    Ok(())
+}
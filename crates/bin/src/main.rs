@@ -1,15 +1,29 @@
+use anyhow::Context;
 use clap::Parser;
-use dsync_hasezoey::{FileChangesStatus, GenerationConfig, TableOptions};
+use dsync_hasezoey::{
+    FileChangesStatus, FileMode, GenerationConfig, GenerationResult, TableOptions, Warning,
+};
 use std::collections::HashMap;
 
 mod clap_conf;
 mod completions;
+mod config;
+mod diff;
 
 fn main() {
     let res = actual_main();
 
     if let Err(err) = res {
         eprintln!("Error:\n{err}");
+
+        // display the location in the schema file, if there is one
+        if let Some(diagnostic) = err
+            .downcast_ref::<dsync_hasezoey::Error>()
+            .and_then(|err| err.get_diagnostic())
+        {
+            eprintln!("{diagnostic}");
+        }
+
         let backtrace = err.backtrace().to_string();
 
         if backtrace == "disabled backtrace" {
@@ -32,71 +46,290 @@ fn actual_main() -> anyhow::Result<()> {
             clap_conf::SubCommands::Completions(subcommand) => {
                 completions::command_completions(&subcommand)
             }
+            clap_conf::SubCommands::Diff(subcommand) => diff::command_diff(*subcommand),
         };
     }
 
-    let args = cli
-        .args
-        .expect("cli.args should be defined if not subcommand is given");
+    // "args" is not defined if no option is given, all options may come from the config file
+    let args = cli.args.unwrap_or_default();
+
+    let check = args.check;
+    let deny_warnings = args.deny_warnings;
+    let verbose = args.verbose;
+
+    let result = generate(args, check.then_some(FileMode::Check))?;
+
+    if verbose {
+        print_autogenerated_columns(&result);
+    }
+
+    let mut modified: usize = 0;
+
+    for change in result.file_changes.iter() {
+        println!("{} {}", change.status, change.file.to_string_lossy());
+        if change.status != FileChangesStatus::Unchanged {
+            modified += 1;
+        }
+    }
+
+    println!("Modified {} files", modified);
+
+    print_warnings(&result.warnings, deny_warnings)?;
+
+    if check {
+        let stale = result.changed_files().count();
+
+        if stale > 0 {
+            anyhow::bail!("{stale} files are out of date, run without \"--check\" to update them");
+        }
+    }
+
+    Ok(())
+}
 
-    let cols = args.autogenerated_columns.unwrap_or_default();
-    let mut default_table_options = TableOptions::default()
-        .autogenerated_columns(cols.iter().map(|t| t.as_str()).collect::<Vec<&str>>());
+/// Print all columns which are autogenerated because of the input or inference to stderr
+fn print_autogenerated_columns(result: &GenerationResult) {
+    for column in result.autogenerated_columns.iter() {
+        eprintln!(
+            "Autogenerated column {}.{} ({})",
+            column.table, column.column, column.reason
+        );
+    }
+}
+
+/// Print all warnings to stderr and error if `deny_warnings` is set and there are any warnings
+fn print_warnings(warnings: &[Warning], deny_warnings: bool) -> anyhow::Result<()> {
+    for warning in warnings.iter() {
+        eprintln!("Warning: {warning}");
+    }
+
+    if deny_warnings && !warnings.is_empty() {
+        anyhow::bail!(
+            "Generation had {} warnings and \"--deny-warnings\" is set",
+            warnings.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Generate the files from the cli options merged with the config file
+/// `file_mode` overwrites the file mode option if set
+fn generate(
+    args: clap_conf::MainOptions,
+    file_mode: Option<FileMode>,
+) -> anyhow::Result<GenerationResult> {
+    let config = config::ConfigFile::load(args.config.as_deref())?.unwrap_or_default();
+
+    let input = args.input.or(config.input).ok_or_else(|| {
+        anyhow::anyhow!("Missing input, set it with \"--input\" or \"input\" in the config file")
+    })?;
+    let output = args.output.or(config.output).ok_or_else(|| {
+        anyhow::anyhow!("Missing output, set it with \"--output\" or \"output\" in the config file")
+    })?;
+    let connection_type = args.connection_type.or(config.connection_type).ok_or_else(|| {
+        anyhow::anyhow!("Missing connection type, set it with \"--connection-type\" or \"connection-type\" in the config file")
+    })?;
+
+    // table options given on the cli, which take precedence over the defaults and tables in the config file
+    let mut cli_table_options = TableOptions::default();
+
+    if let Some(ref cols) = args.autogenerated_columns {
+        cli_table_options
+            .set_autogenerated_columns(cols.iter().map(|t| t.as_str()).collect::<Vec<&str>>());
+    }
+
+    if let Some(infer_autogenerated) = args.infer_autogenerated {
+        cli_table_options.set_infer_autogenerated(infer_autogenerated);
+    }
+
+    if let Some(ref patterns) = args.autogenerated_patterns {
+        cli_table_options
+            .set_autogenerated_patterns(patterns.iter().map(|t| t.as_str()).collect::<Vec<&str>>());
+    }
 
     #[cfg(feature = "tsync")]
-    if args.tsync {
-        default_table_options = default_table_options.tsync();
+    if let Some(tsync) = args.tsync {
+        cli_table_options.set_tsync(tsync);
     }
 
     #[cfg(feature = "async")]
-    if args.use_async {
-        default_table_options = default_table_options.use_async();
+    if let Some(use_async) = args.use_async {
+        cli_table_options.set_async(use_async);
     }
 
-    if args.no_serde {
-        default_table_options = default_table_options.disable_serde();
+    if let Some(no_serde) = args.no_serde {
+        cli_table_options.set_serde(!no_serde);
     }
 
-    if args.only_necessary_derives {
-        default_table_options = default_table_options.only_necessary_derives();
+    if let Some(only_necessary_derives) = args.only_necessary_derives {
+        cli_table_options.set_only_necessary_derives(only_necessary_derives);
     }
 
-    if args.no_impls {
-        default_table_options = default_table_options.disable_impls();
+    if let Some(no_impls) = args.no_impls {
+        cli_table_options.set_generate_impls(!no_impls);
     }
 
-    if args.create_str {
-        default_table_options = default_table_options.create_str_over_string();
+    if let Some(create_str) = args.create_str {
+        cli_table_options.set_create_str(create_str);
     }
 
-    let changes = dsync_hasezoey::generate_files(
-        args.input,
-        args.output,
-        GenerationConfig {
-            default_table_options,
-            table_options: HashMap::from([]),
-            connection_type: args.connection_type,
-            schema_path: args.schema_path,
-            model_path: args.model_path,
-            once_common_structs: args.once_common_structs,
-            single_model_file: args.single_model_file,
-            file_mode: args.file_mode.into(),
-            read_only_prefix: args.read_only_prefix,
-            once_connection: args.once_connection,
-            lessen_conflicts: args.lessen_conflicts,
-        },
-    )?;
+    if let Some(update_strategy) = args.update_strategy {
+        cli_table_options.set_update_strategy(update_strategy.into());
+    }
 
-    let mut modified: usize = 0;
+    if let Some(borrowed_types) = args.borrowed_types {
+        cli_table_options.set_borrowed_types(borrowed_types.into());
+    }
 
-    for change in changes {
-        println!("{} {}", change.status, change.file.to_string_lossy());
-        if change.status != FileChangesStatus::Unchanged {
-            modified += 1;
-        }
+    if let Some(filter_struct) = args.filter_struct {
+        cli_table_options.set_filter_struct(filter_struct);
     }
 
-    println!("Modified {} files", modified);
+    let default_table_options =
+        cli_table_options.apply_defaults(&config.defaults.to_table_options()?);
 
-    Ok(())
+    let table_options = config
+        .tables
+        .iter()
+        .map(|(name, table)| {
+            let options = table
+                .to_table_options()
+                .with_context(|| format!("Invalid options for table \"{name}\""))?;
+
+            Ok((name.as_str(), cli_table_options.apply_defaults(&options)))
+        })
+        .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+    let mut sql_type_variants: HashMap<&str, Vec<&str>> = config
+        .sql_type_variants
+        .iter()
+        .map(|(name, variants)| (name.as_str(), variants.iter().map(String::as_str).collect()))
+        .collect();
+
+    let sql_type_variants_args = args.sql_type_variants.unwrap_or_default();
+
+    for arg in sql_type_variants_args.iter() {
+        let (name, variants) =
+            parse_key_value(arg, "--sql-type-variants", "name=variant1,variant2")?;
+
+        sql_type_variants.insert(name, variants.split(',').map(str::trim).collect());
+    }
+
+    let mut sql_type_overrides: HashMap<&str, &str> = config
+        .sql_type_overrides
+        .iter()
+        .map(|(name, ty)| (name.as_str(), ty.as_str()))
+        .collect();
+
+    let sql_type_overrides_args = args.sql_type_overrides.unwrap_or_default();
+
+    for arg in sql_type_overrides_args.iter() {
+        let (name, ty) = parse_key_value(arg, "--sql-type-override", "SqlType=rust::Type")?;
+
+        sql_type_overrides.insert(name, ty);
+    }
+
+    let mut column_type_overrides: HashMap<&str, &str> = config
+        .column_type_overrides
+        .iter()
+        .map(|(column, ty)| (column.as_str(), ty.as_str()))
+        .collect();
+
+    let column_type_overrides_args = args.column_type_overrides.unwrap_or_default();
+
+    for arg in column_type_overrides_args.iter() {
+        let (column, ty) =
+            parse_key_value(arg, "--column-type-override", "table.column=rust::Type")?;
+
+        column_type_overrides.insert(column, ty);
+    }
+
+    let mut singular_overrides: HashMap<&str, &str> = config
+        .singular_overrides
+        .iter()
+        .map(|(plural, singular)| (plural.as_str(), singular.as_str()))
+        .collect();
+
+    let singular_overrides_args = args.singular_overrides.unwrap_or_default();
+
+    for arg in singular_overrides_args.iter() {
+        let (plural, singular) = parse_key_value(arg, "--singular-override", "plural=singular")?;
+
+        singular_overrides.insert(plural, singular);
+    }
+
+    Ok(dsync_hasezoey::generate_files(
+        input,
+        output,
+        GenerationConfig {
+            default_table_options,
+            table_options,
+            connection_type,
+            schema_path: args
+                .schema_path
+                .or(config.schema_path)
+                .unwrap_or_else(|| "crate::schema::".into()),
+            model_path: args
+                .model_path
+                .or(config.model_path)
+                .unwrap_or_else(|| "crate::models::".into()),
+            once_common_structs: args
+                .once_common_structs
+                .or(config.once_common_structs)
+                .unwrap_or_default(),
+            single_model_file: args
+                .single_model_file
+                .or(config.single_model_file)
+                .unwrap_or_default(),
+            file_mode: file_mode.unwrap_or_else(|| {
+                args.file_mode
+                    .or(config.file_mode)
+                    .unwrap_or(clap_conf::FileMode::Overwrite)
+                    .into()
+            }),
+            read_only_prefix: args.read_only_prefix.or(config.read_only_prefix),
+            once_connection: args
+                .once_connection
+                .or(config.once_connection)
+                .unwrap_or_default(),
+            lessen_conflicts: args
+                .lessen_conflicts
+                .or(config.lessen_conflicts)
+                .unwrap_or_default(),
+            sql_type_variants,
+            sql_type_overrides,
+            column_type_overrides,
+            datetime_backend: args
+                .datetime_backend
+                .or(config.datetime_backend)
+                .map(Into::into)
+                .unwrap_or_default(),
+            ip_network_backend: args
+                .ip_network_backend
+                .or(config.ip_network_backend)
+                .map(Into::into)
+                .unwrap_or_default(),
+            backend: args.backend.or(config.backend).map(Into::into),
+            range_type: args.range_type.or(config.range_type).unwrap_or_default(),
+            singularize: args.singularize.or(config.singularize).unwrap_or_default(),
+            singular_overrides,
+            read_struct_name: args.read_struct_name.or(config.read_struct_name),
+            create_struct_name: args.create_struct_name.or(config.create_struct_name),
+            update_struct_name: args.update_struct_name.or(config.update_struct_name),
+        },
+    )?)
+}
+
+/// Split a "key=value" cli argument, `format` is used in the error message
+fn parse_key_value<'a>(
+    arg: &'a str,
+    option: &str,
+    format: &str,
+) -> anyhow::Result<(&'a str, &'a str)> {
+    let (key, value) = arg.split_once('=').ok_or_else(|| {
+        anyhow::anyhow!("Expected \"{option}\" to be in format \"{format}\", got \"{arg}\"")
+    })?;
+
+    Ok((key.trim(), value.trim()))
 }
@@ -1,7 +1,10 @@
 use heck::{ToPascalCase, ToSnakeCase};
 
-use crate::parser::{ParsedTableMacro, FILE_SIGNATURE};
-use crate::{GenerationConfig, Result, TableOptions};
+use crate::parser::{
+    path_with_schema, to_rust_ident, ParsedColumnMacro, ParsedColumnType, ParsedSqlType,
+    ParsedTableMacro, FILE_SIGNATURE,
+};
+use crate::{GenerationConfig, Result, TableOptions, UpdateStrategy};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StructType {
@@ -33,8 +36,21 @@ impl StructType {
         }
     }
 
-    /// Get the formatted struct with prefix and suffix applied
-    pub fn format(&self, name: &'_ str) -> String {
+    /// Get the struct name template from the config, if set
+    fn name_template<'b>(&self, config: &'b GenerationConfig) -> Option<&'b str> {
+        match self {
+            StructType::Read => config.read_struct_name.as_deref(),
+            StructType::Update => config.update_struct_name.as_deref(),
+            StructType::Create => config.create_struct_name.as_deref(),
+        }
+    }
+
+    /// Get the formatted struct with the name template from the config applied, or with the default prefix and suffix
+    pub fn format(&self, name: &'_ str, config: &GenerationConfig) -> String {
+        if let Some(template) = self.name_template(config) {
+            return template.replace("{name}", name);
+        }
+
         format!(
             "{struct_prefix}{struct_name}{struct_suffix}",
             struct_prefix = self.prefix(),
@@ -44,6 +60,41 @@ impl StructType {
     }
 }
 
+/// Which fields a column gets in the "*Filter" struct, besides the one for equality
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FilterKind {
+    /// Only equality, like for booleans
+    Equal,
+    /// Also "*_gte" and "*_lte" fields, like for numbers and dates
+    Range,
+    /// Also a "*_like" field, for text
+    Like,
+}
+
+impl FilterKind {
+    /// Get the kind of filter for a column, [None] if the column cannot be compared (like `Json`)
+    fn for_column(column: &ParsedColumnMacro) -> Option<Self> {
+        Some(match column.sql_type.strip_nullable() {
+            ParsedColumnType::Base(ident) => match ident.to_string().to_lowercase().as_str() {
+                "tinyint" | "smallint" | "smallserial" | "int2" | "int4" | "integer" | "serial"
+                | "bigint" | "bigserial" | "int8" | "float" | "float4" | "double" | "float8"
+                | "numeric" | "decimal" | "date" | "datetime" | "time" | "timestamp"
+                | "timestamptz" | "timestamptzsqlite" => FilterKind::Range,
+                "text" | "varchar" | "bpchar" | "char" | "tinytext" | "mediumtext" | "longtext" => {
+                    FilterKind::Like
+                }
+                // postgres has no equality operator for "json" and ranges are not compared by value
+                "json" | "int4range" | "int8range" | "numrange" | "daterange" | "tsrange"
+                | "tstzrange" => return None,
+                _ => FilterKind::Equal,
+            },
+            ParsedColumnType::Unsigned(_) => FilterKind::Range,
+            ParsedColumnType::Array(_) => FilterKind::Equal,
+            ParsedColumnType::Record(_) | ParsedColumnType::Nullable(_) => return None,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct Struct<'a> {
     /// Struct name
@@ -54,7 +105,7 @@ pub struct Struct<'a> {
     table: &'a ParsedTableMacro,
     /// Options for the current table
     opts: TableOptions<'a>,
-    /// Generator wide config (original config)
+    /// Config used for the names of other structs
     config: &'a GenerationConfig<'a>,
     /// Storage for the once rendered code
     rendered_code: Option<String>,
@@ -70,6 +121,14 @@ pub struct StructField {
     pub base_type: String,
     /// Actual table column name
     pub column_name: String,
+    /// Comment of the column in the database, if known
+    pub comment: Option<String>,
+    /// Whether the field is skipped in `AsChangeset`, because it is a read-only column
+    pub skip_update: bool,
+    /// Whether the column is nullable, then `base_type` is already a [Option]
+    pub is_nullable: bool,
+    /// Whether the column is a postgres `Record`, which diesel can only read
+    pub is_record: bool,
 
     pub is_optional: bool,
 }
@@ -79,14 +138,14 @@ impl<'a> Struct<'a> {
     pub fn new(
         ty: StructType,
         table: &'a ParsedTableMacro,
-        config: &'a GenerationConfig<'_>,
+        config: &'a GenerationConfig<'a>,
     ) -> Self {
         let mut obj = Self {
-            identifier: ty.format(table.struct_name.as_str()),
+            identifier: ty.format(table.struct_name.as_str(), config),
             opts: config.table(&table.name.to_string()),
+            config,
             table,
             ty,
-            config,
             rendered_code: None,
             has_fields: None,
         };
@@ -126,10 +185,22 @@ impl<'a> Struct<'a> {
     const DERIVE_Serde_Serialize: &'static str = "Serialize";
     const DERIVE_Serde_Deserialize: &'static str = "Deserialize";
 
+    /// Get whether the struct derives `AsChangeset`, which is only the case if there is any field to update
+    fn derives_as_changeset(&self) -> bool {
+        let fields = self.fields();
+
+        !fields
+            .iter()
+            .all(|f| f.skip_update || self.table.primary_key_column_names().contains(&f.name))
+            && !self.opts.get_read_only()
+            // diesel can not write "Record" columns
+            && !fields.iter().any(|f| f.is_record)
+    }
+
     fn attr_derive(&self) -> String {
         let mut derives: Vec<&str> = Vec::from(Self::DERIVES_DEFAULT);
 
-        if self.config.default_table_options.get_serde() {
+        if self.opts.get_serde() {
             derives.push(Self::DERIVE_Serde_Serialize);
             derives.push(Self::DERIVE_Serde_Deserialize);
         }
@@ -140,9 +211,13 @@ impl<'a> Struct<'a> {
             derives.push(Self::DERIVE_Queryable);
         }
 
+        // diesel can not write "Record" columns, they are only in the main struct
+        let has_record_fields = self.fields().iter().any(|f| f.is_record);
+
         if (!self.opts.get_only_necessary_derives()
             || (self.opts.get_only_necessary_derives() && self.ty == StructType::Create))
             && !self.opts.get_read_only()
+            && !has_record_fields
         {
             derives.push(Self::DERIVE_Insertable);
         }
@@ -150,7 +225,8 @@ impl<'a> Struct<'a> {
         if self.ty == StructType::Read {
             derives.push(Self::DERIVE_Selectable);
 
-            if !self.table.foreign_keys.is_empty() {
+            // parents need to be identifiable for the "belonging_to" of their children
+            if !self.table.foreign_keys.is_empty() || !self.table.children.is_empty() {
                 derives.push(Self::DERIVE_Identifiable);
             }
 
@@ -159,12 +235,7 @@ impl<'a> Struct<'a> {
             }
         }
 
-        if !self
-            .fields()
-            .iter()
-            .all(|f| self.table.primary_key_column_names().contains(&f.name))
-            && !self.opts.get_read_only()
-        {
+        if self.derives_as_changeset() {
             derives.push(Self::DERIVE_AsChangeset)
         }
 
@@ -178,12 +249,19 @@ impl<'a> Struct<'a> {
             .columns
             .iter()
             .filter(|c| {
-                let is_autogenerated = self
-                    .opts
-                    .autogenerated_columns
-                    .as_deref()
-                    .unwrap_or_default()
-                    .contains(&c.name.to_string().as_str());
+                let column_name = c.name.to_string();
+                let is_listed = |cols: &[&str]| cols.contains(&column_name.as_str());
+                let is_autogenerated = c.autogenerated.is_some()
+                    || is_listed(
+                        self.opts
+                            .autogenerated_columns
+                            .as_deref()
+                            .unwrap_or_default(),
+                    );
+
+                if is_listed(self.opts.get_exclude_columns()) {
+                    return false;
+                }
 
                 match self.ty {
                     StructType::Read => true,
@@ -191,16 +269,21 @@ impl<'a> Struct<'a> {
                         let is_pk = self.table.primary_key_columns.contains(&c.name);
 
                         !is_pk
+                            && !c.sql_type.contains_record()
+                            && !is_listed(self.opts.get_update_exclude())
+                            && !is_listed(self.opts.get_read_only_columns())
+                    }
+                    StructType::Create => {
+                        !is_autogenerated
+                            && !c.sql_type.contains_record()
+                            && !is_listed(self.opts.get_create_exclude())
                     }
-                    StructType::Create => !is_autogenerated,
                 }
             })
             .map(|c| {
                 let name = c.name.to_string();
                 let base_type = if c.is_nullable {
                     format!("Option<{}>", c.ty)
-                } else if c.is_unsigned {
-                    c.ty.replace('i', "u")
                 } else {
                     c.ty.clone()
                 };
@@ -211,28 +294,37 @@ impl<'a> Struct<'a> {
                     .primary_key_columns
                     .iter()
                     .any(|pk| pk.to_string().eq(name.as_str()));
-                let is_autogenerated = self
-                    .opts
-                    .autogenerated_columns
-                    .as_deref()
-                    .unwrap_or_default()
-                    .contains(&c.name.to_string().as_str());
+                let is_autogenerated = c.autogenerated.is_some()
+                    || self
+                        .opts
+                        .autogenerated_columns
+                        .as_deref()
+                        .unwrap_or_default()
+                        .contains(&c.name.to_string().as_str());
                 // let is_fk = table.foreign_keys.iter().any(|fk| fk.1.to_string().eq(field_name.as_str()));
 
                 match self.ty {
                     StructType::Read => {}
                     StructType::Update => {
                         // all non-key fields should be optional in Form structs (to allow partial updates)
-                        is_optional = !is_pk || is_autogenerated;
+                        is_optional = self.opts.get_update_strategy() == UpdateStrategy::Patch
+                            && (!is_pk || is_autogenerated);
                     }
                     StructType::Create => {}
                 }
 
+                let skip_update = self.ty == StructType::Read
+                    && self.opts.get_read_only_columns().contains(&name.as_str());
+
                 StructField {
                     name,
                     base_type,
                     is_optional,
+                    skip_update,
+                    is_nullable: c.is_nullable,
+                    is_record: c.sql_type.contains_record(),
                     column_name: c.column_name.clone(),
+                    comment: c.comment.clone(),
                 }
             })
             .collect()
@@ -263,7 +355,7 @@ impl<'a> Struct<'a> {
             .map(|fk| {
                 format!(
                     ", belongs_to({foreign_table_name}, foreign_key={join_column})",
-                    foreign_table_name = fk.0.to_string().to_pascal_case(),
+                    foreign_table_name = read_struct_name(&fk.0.to_string(), self.config),
                     join_column = fk.1
                 )
             })
@@ -271,55 +363,116 @@ impl<'a> Struct<'a> {
             .join(" ");
 
         let fields = self.fields();
+        let derives_as_changeset = self.derives_as_changeset();
         let mut lines = vec![];
         let mut require_lifetimes = false;
         for field in fields.iter() {
             let field_name = &field.name;
-            let base_type: &str = if self.ty == StructType::Create
+            let borrowed_types = match self.ty {
+                StructType::Read => None,
+                StructType::Create | StructType::Update => self.opts.get_borrowed_types(),
+            };
+            let owned_type = if field.is_nullable {
+                field
+                    .base_type
+                    .strip_prefix("Option<")
+                    .and_then(|ty| ty.strip_suffix('>'))
+                    .unwrap_or(&field.base_type)
+            } else {
+                &field.base_type
+            };
+            let borrowed_type = borrowed_types
+                .and_then(|borrowed| borrowed.borrowed_type(owned_type, self.opts.get_serde()));
+
+            let base_type: String = if let Some(borrowed_type) = borrowed_type {
+                require_lifetimes = true;
+
+                if field.is_nullable {
+                    format!("Option<{borrowed_type}>")
+                } else {
+                    borrowed_type
+                }
+            } else if self.ty == StructType::Create
                 && field.base_type == "String"
                 && self.opts.get_create_str()
             {
                 require_lifetimes = true;
-                "&'a str"
+                "&'a str".to_string()
             } else {
-                &field.base_type
+                field.base_type.clone()
             };
             let field_type = if field.is_optional {
                 format!("Option<{}>", base_type)
             } else {
-                base_type.into()
+                base_type.clone()
+            };
+            // serde only borrows "&'a str" and "&'a [u8]" implicitly
+            let serde_borrow_attr = if self.opts.get_serde()
+                && field_type.contains("'a")
+                && field_type != "&'a str"
+                && field_type != "&'a [u8]"
+            {
+                "\n    #[serde(borrow)]"
+            } else {
+                ""
+            };
+
+            // the column comment is added as a separate doc paragraph
+            let comment_doc: String = field
+                .comment
+                .iter()
+                .flat_map(|comment| comment.lines())
+                .map(|line| match line.trim_end() {
+                    "" => "\n    ///".to_string(),
+                    line => format!("\n    /// {line}"),
+                })
+                .collect();
+            let comment_doc = if comment_doc.is_empty() {
+                comment_doc
+            } else {
+                format!("\n    ///{comment_doc}")
+            };
+
+            // "skip_update" is only known to diesel 2.3, so it is only set where it is required
+            let skip_update_attr = if field.skip_update && derives_as_changeset {
+                "\n    #[diesel(skip_update)]"
+            } else {
+                ""
+            };
+            // without "double_option", serde would deserialize both a missing field and "null" as "None"
+            let double_option_attr = if field.is_optional
+                && field.is_nullable
+                && self.opts.get_serde()
+            {
+                "\n    #[serde(default, skip_serializing_if = \"Option::is_none\", with = \"::serde_with::rust::double_option\")]"
+            } else {
+                ""
             };
 
             lines.push(format!(
-                r#"    /// Field Representing column `{column_name}`
+                r#"    /// Field Representing column `{column_name}`{comment_doc}{skip_update_attr}{serde_borrow_attr}{double_option_attr}
     pub {field_name}: {field_type},"#,
                 column_name = field.column_name
             ));
         }
 
         let table_name = &table.name;
+        let read_struct_name = StructType::Read.format(&table.struct_name, self.config);
         let struct_doc = match self.ty {
             StructType::Read => format!("/// Struct representing a row for table `{table_name}`\n"),
-            StructType::Update => format!(
-                "/// Update struct for [`{struct_name}`] on table `{table_name}`\n",
-                struct_name = table.struct_name
-            ),
-            StructType::Create => format!(
-                "/// Create struct for [`{struct_name}`] on table `{table_name}`\n",
-                struct_name = table.struct_name
-            ),
+            StructType::Update => {
+                format!("/// Update struct for [`{read_struct_name}`] on table `{table_name}`\n",)
+            }
+            StructType::Create => {
+                format!("/// Create struct for [`{read_struct_name}`] on table `{table_name}`\n",)
+            }
         };
 
-        let lifetimes =
-            if require_lifetimes && self.ty == StructType::Create && self.opts.get_create_str() {
-                "<'a>"
-            } else {
-                ""
-            };
+        let lifetimes = if require_lifetimes { "<'a>" } else { "" };
 
         let struct_code = format!(
             "{struct_doc}{tsync_attr}{derive_attr}
-#[diesel(table_name={table_name}{primary_key}{belongs_to})]
+#[diesel(table_name={table_name}{primary_key}{belongs_to}{treat_none_as_null})]
 pub struct {struct_name}{lifetimes} {{
 {lines}
 }}\n",
@@ -327,7 +480,7 @@ pub struct {struct_name}{lifetimes} {{
             derive_attr = self.attr_derive(),
             table_name = table.name,
             lines = lines.join("\n"),
-            struct_name = ty.format(table.struct_name.as_str()),
+            struct_name = self.identifier,
             primary_key = if ty != StructType::Read {
                 "".to_string()
             } else {
@@ -337,6 +490,13 @@ pub struct {struct_name}{lifetimes} {{
                 "".to_string()
             } else {
                 belongs_to
+            },
+            treat_none_as_null = if ty == StructType::Update
+                && self.opts.get_update_strategy() == UpdateStrategy::Replace
+            {
+                ", treat_none_as_null = true"
+            } else {
+                ""
             }
         );
 
@@ -416,11 +576,26 @@ fn build_table_fns(
     };
     #[cfg(not(feature = "async"))]
     let await_keyword = "";
-    let struct_name = &table.struct_name;
-    let schema_path = &config.schema_path;
+    let struct_name = StructType::Read.format(&table.struct_name, config);
+    let schema_path = table.schema_path(config);
     let create_struct_identifier = &create_struct.identifier;
     let update_struct_identifier = &update_struct.identifier;
 
+    // the main struct does not have all columns if any are excluded, so only its columns can be selected
+    let is_partial = table.columns.iter().any(|column| {
+        table_options
+            .get_exclude_columns()
+            .contains(&column.name.to_string().as_str())
+    });
+    let (select_self, returning_self) = if is_partial {
+        (
+            ".select(Self::as_select())",
+            ".returning(Self::as_returning())",
+        )
+    } else {
+        ("", "")
+    };
+
     let mut buffer = String::new();
 
     if !config.once_common_structs {
@@ -442,7 +617,7 @@ fn build_table_fns(
     pub{async_keyword} fn create(db: &mut {connection_type_path}, item: &{create_struct_identifier}) -> QueryResult<Self> {{
         use {schema_path}{table_name}::dsl::*;
 
-        insert_into({table_name}).values(item).get_result::<Self>(db){await_keyword}
+        insert_into({table_name}).values(item){returning_self}.get_result::<Self>(db){await_keyword}
     }}
 "##
         ));
@@ -453,7 +628,7 @@ fn build_table_fns(
     pub{async_keyword} fn create(db: &mut {connection_type_path}) -> QueryResult<Self> {{
         use {schema_path}{table_name}::dsl::*;
 
-        insert_into({table_name}).default_values().get_result::<Self>(db){await_keyword}
+        insert_into({table_name}).default_values(){returning_self}.get_result::<Self>(db){await_keyword}
     }}
 "##
         ));
@@ -465,7 +640,7 @@ fn build_table_fns(
     pub{async_keyword} fn read(db: &mut {connection_type_path}, {item_id_params}) -> QueryResult<Self> {{
         use {schema_path}{table_name}::dsl::*;
 
-        {table_name}.{item_id_filters}.first::<Self>(db){await_keyword}
+        {table_name}.{item_id_filters}{select_self}.first::<Self>(db){await_keyword}
     }}
 "##
     ));
@@ -476,14 +651,103 @@ fn build_table_fns(
         "PaginationResult"
     };
 
+    // pages are ordered by the primary key, otherwise the database may return rows in any order
+    // without a primary key the order is left to the database, because "order_by(())" does not compile
+    let primary_key_order = match primary_column_name_and_type.as_slice() {
+        [] => String::new(),
+        [(name, _)] => format!(".order_by({name})"),
+        columns => format!(
+            ".order_by(({}))",
+            columns
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        ),
+    };
+
     buffer.push_str(&format!(r##"
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub{async_keyword} fn paginate(db: &mut {connection_type_path}, page: i64, page_size: i64) -> QueryResult<{pagination_result_path}<Self>> {{
         use {schema_path}{table_name}::dsl::*;
 
         let page_size = if page_size < 1 {{ 1 }} else {{ page_size }};
         let total_items = {table_name}.count().get_result(db){await_keyword}?;
-        let items = {table_name}.limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
+        let items = {table_name}{primary_key_order}{select_self}.limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
+
+        Ok({pagination_result_path} {{
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        }})
+    }}
+"##));
+
+    buffer.push_str(&build_paginate_ordered_fn(table, config));
+
+    if table_options.get_filter_struct() {
+        buffer.push_str(&build_filter_fn(table, config));
+    }
+
+    let foreign_key_columns: Vec<&str> = table
+        .foreign_keys
+        .iter()
+        .map(|(_, join_column)| join_column.as_str())
+        .collect();
+
+    for column in table.columns.iter() {
+        let column_name = column.name.to_string();
+        let is_unique = table_options
+            .get_unique_columns()
+            .contains(&column_name.as_str());
+        let is_indexed = foreign_key_columns.contains(&column_name.as_str())
+            || table_options
+                .get_indexed_columns()
+                .contains(&column_name.as_str());
+
+        if !is_unique && !is_indexed {
+            continue;
+        }
+
+        // nullable columns are filtered by a value, as "= NULL" never matches
+        let column_type = &column.ty;
+        let fn_suffix = column_name.trim_start_matches("r#");
+        // by reference, as the value is used for both the count and the items when paginating
+        let column_filter = format!("filter({column_name}.eq(&param_{fn_suffix}))");
+
+        // there is at most one row for a unique column, so it does not need to be paginated
+        if is_unique {
+            buffer.push_str(&format!(
+                r##"
+    /// Get the row with the given value in unique column `{column_name}`
+    pub{async_keyword} fn filter_by_{fn_suffix}(db: &mut {connection_type_path}, param_{fn_suffix}: {column_type}) -> QueryResult<Self> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {table_name}.{column_filter}{select_self}.first::<Self>(db){await_keyword}
+    }}
+"##
+            ));
+            continue;
+        }
+
+        buffer.push_str(&format!(r##"
+    /// Get all rows with the given value in column `{column_name}`
+    pub{async_keyword} fn filter_by_{fn_suffix}(db: &mut {connection_type_path}, param_{fn_suffix}: {column_type}) -> QueryResult<Vec<Self>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        {table_name}.{column_filter}{select_self}.load::<Self>(db){await_keyword}
+    }}
+
+    /// Paginates through the rows with the given value in column `{column_name}` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub{async_keyword} fn paginate_by_{fn_suffix}(db: &mut {connection_type_path}, param_{fn_suffix}: {column_type}, page: i64, page_size: i64) -> QueryResult<{pagination_result_path}<Self>> {{
+        use {schema_path}{table_name}::dsl::*;
+
+        let page_size = if page_size < 1 {{ 1 }} else {{ page_size }};
+        let total_items = {table_name}.{column_filter}.count().get_result(db){await_keyword}?;
+        let items = {table_name}.{column_filter}{primary_key_order}{select_self}.limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
 
         Ok({pagination_result_path} {{
             items,
@@ -495,6 +759,7 @@ fn build_table_fns(
         }})
     }}
 "##));
+    }
 
     // TODO: If primary key columns are attached to the form struct (not optionally)
     // then don't require item_id_params (otherwise it'll be duplicated)
@@ -510,7 +775,7 @@ fn build_table_fns(
     pub{async_keyword} fn update(db: &mut {connection_type_path}, {item_id_params}, item: &{update_struct_identifier}) -> QueryResult<Self> {{
         use {schema_path}{table_name}::dsl::*;
 
-        diesel::update({table_name}.{item_id_filters}).set(item).get_result(db){await_keyword}
+        diesel::update({table_name}.{item_id_filters}).set(item){returning_self}.get_result(db){await_keyword}
     }}
 "##));
     }
@@ -528,6 +793,158 @@ fn build_table_fns(
         ));
     }
 
+    for (foreign_table_name, join_column) in table.foreign_keys.iter() {
+        let parent_struct_name = read_struct_name(&foreign_table_name.to_string(), config);
+        // like "user" for column "user_id"
+        let relation_name = to_rust_ident(
+            join_column
+                .strip_suffix("_id")
+                .unwrap_or(join_column.as_str()),
+        );
+        let is_nullable = table
+            .columns
+            .iter()
+            .any(|column| column.name == join_column.as_str() && column.is_nullable);
+
+        buffer.push_str(&format!(
+            r##"
+    /// Get all rows which belong to the given [`{parent_struct_name}`]
+    pub{async_keyword} fn for_{relation_name}(db: &mut {connection_type_path}, parent: &{parent_struct_name}) -> QueryResult<Vec<Self>> {{
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db){await_keyword}
+    }}
+"##
+        ));
+
+        let parent_query = format!("{schema_path}{foreign_table_name}::table.find(fk).select({parent_struct_name}::as_select()).first::<{parent_struct_name}>(db){await_keyword}");
+
+        if is_nullable {
+            buffer.push_str(&format!(
+                r##"
+    /// Get the [`{parent_struct_name}`] this row belongs to with column `{join_column}`, if set
+    pub{async_keyword} fn {relation_name}(&self, db: &mut {connection_type_path}) -> QueryResult<Option<{parent_struct_name}>> {{
+        match &self.{join_column} {{
+            Some(fk) => {parent_query}.map(Some),
+            None => Ok(None),
+        }}
+    }}
+"##
+            ));
+        } else {
+            buffer.push_str(&format!(
+                r##"
+    /// Get the [`{parent_struct_name}`] this row belongs to with column `{join_column}`
+    pub{async_keyword} fn {relation_name}(&self, db: &mut {connection_type_path}) -> QueryResult<{parent_struct_name}> {{
+        let fk = &self.{join_column};
+
+        {parent_query}
+    }}
+"##
+            ));
+        }
+    }
+
+    for (child_table_name, _) in table.children.iter() {
+        let child_struct_name = read_struct_name(&child_table_name.to_string(), config);
+        let child_fn_name = child_table_name.to_string().to_snake_case();
+
+        buffer.push_str(&format!(
+            r##"
+    /// Load all rows of table `{child_table_name}` which belong to the given rows, grouped by the given rows
+    pub{async_keyword} fn load_{child_fn_name}(db: &mut {connection_type_path}, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<{child_struct_name}>)>> {{
+        let children = {child_struct_name}::belonging_to(parents).select({child_struct_name}::as_select()).load::<{child_struct_name}>(db){await_keyword}?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }}
+"##
+        ));
+    }
+
+    // the join table references the primary key of this table, so it has to be a single column
+    let many_to_many = match primary_column_name_and_type.as_slice() {
+        [(primary_key, _)] => table
+            .many_to_many
+            .iter()
+            .map(|relation| (primary_key, relation))
+            .collect(),
+        _ => vec![],
+    };
+
+    for (primary_key, relation) in many_to_many {
+        let join_table = format!("{schema_path}{}", relation.join_table);
+        let column = &relation.column;
+        let other_table_name = &relation.other_table;
+        let other_column = &relation.other_column;
+        let other_column_type = &relation.other_column_type;
+        let other_struct_name = read_struct_name(&other_table_name.to_string(), config);
+        let other_fn_name = other_table_name.to_string().to_snake_case();
+        // like "role" for column "role_id"
+        let link_name = to_rust_ident(
+            other_column
+                .strip_suffix("_id")
+                .unwrap_or(other_column.as_str()),
+        );
+
+        buffer.push_str(&format!(
+            r##"
+    /// Get all [`{other_struct_name}`]s linked to this row through table `{join_table_name}`
+    pub{async_keyword} fn {other_fn_name}(&self, db: &mut {connection_type_path}) -> QueryResult<Vec<{other_struct_name}>> {{
+        {schema_path}{other_table_name}::table.inner_join({join_table}::table).filter({join_table}::{column}.eq(&self.{primary_key})).select({other_struct_name}::as_select()).load::<{other_struct_name}>(db){await_keyword}
+    }}
+"##,
+            join_table_name = relation.join_table
+        ));
+
+        if config
+            .table(&relation.join_table.to_string())
+            .get_read_only()
+        {
+            continue;
+        }
+
+        #[cfg(feature = "async")]
+        let (transaction_start, transaction_end) = if table_options.get_async() {
+            (
+                "use diesel_async::scoped_futures::ScopedFutureExt;\n\n        diesel_async::AsyncConnection::transaction(db, |db| async move {",
+                "}.scope_boxed()).await",
+            )
+        } else {
+            ("diesel::Connection::transaction(db, |db| {", "})")
+        };
+        #[cfg(not(feature = "async"))]
+        let (transaction_start, transaction_end) =
+            ("diesel::Connection::transaction(db, |db| {", "})");
+
+        buffer.push_str(&format!(
+            r##"
+    /// Link the row of table `{other_table_name}` with the given `{other_column}` to this row, by inserting into table `{join_table_name}`
+    pub{async_keyword} fn link_{link_name}(&self, db: &mut {connection_type_path}, {other_column}: {other_column_type}) -> QueryResult<usize> {{
+        diesel::insert_into({join_table}::table).values(({join_table}::{column}.eq(&self.{primary_key}), {join_table}::{other_column}.eq({other_column}))).execute(db){await_keyword}
+    }}
+
+    /// Unlink the row of table `{other_table_name}` with the given `{other_column}` from this row, by deleting from table `{join_table_name}`
+    pub{async_keyword} fn unlink_{link_name}(&self, db: &mut {connection_type_path}, {other_column}: {other_column_type}) -> QueryResult<usize> {{
+        diesel::delete({join_table}::table.filter({join_table}::{column}.eq(&self.{primary_key})).filter({join_table}::{other_column}.eq({other_column}))).execute(db){await_keyword}
+    }}
+
+    /// Replace all links of this row in table `{join_table_name}` with links to the rows of table `{other_table_name}` with the given ids, in a transaction
+    pub{async_keyword} fn set_{other_fn_name}(&self, db: &mut {connection_type_path}, ids: &[{other_column_type}]) -> QueryResult<()> {{
+        {transaction_start}
+            diesel::delete({join_table}::table.filter({join_table}::{column}.eq(&self.{primary_key}))).execute(db){await_keyword}?;
+
+            if !ids.is_empty() {{
+                let rows = ids.iter().map(|id| ({join_table}::{column}.eq(&self.{primary_key}), {join_table}::{other_column}.eq(id))).collect::<Vec<_>>();
+                diesel::insert_into({join_table}::table).values(rows).execute(db){await_keyword}?;
+            }}
+
+            Ok::<(), diesel::result::Error>(())
+        {transaction_end}
+    }}
+"##,
+            join_table_name = relation.join_table
+        ));
+    }
+
     buffer.push_str(
         r##"
 }"##,
@@ -536,6 +953,402 @@ fn build_table_fns(
     Ok(buffer)
 }
 
+/// Get all columns which can be filtered by in the "*Filter" struct, with which filters they get
+fn filter_columns<'a>(
+    table: &'a ParsedTableMacro,
+    table_options: &TableOptions,
+) -> Vec<(&'a ParsedColumnMacro, FilterKind)> {
+    table
+        .columns
+        .iter()
+        .filter(|column| {
+            !table_options
+                .get_exclude_columns()
+                .contains(&column.name.to_string().as_str())
+        })
+        .filter_map(|column| Some((column, FilterKind::for_column(column)?)))
+        .collect()
+}
+
+/// Get the name of the "*Filter" struct for a table
+fn filter_struct_name(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    format!(
+        "{}Filter",
+        StructType::Read.format(&table.struct_name, config)
+    )
+}
+
+/// Generate the "*Filter" struct, which has a optional field for each filter of each column
+///
+/// Returns a empty string if there is no column which can be filtered by
+fn build_filter_struct(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    let table_options = config.table(&table.name.to_string());
+    let mut lines = vec![];
+
+    for (column, kind) in filter_columns(table, &table_options) {
+        let field_name = column.name.to_string();
+        let field_suffix = field_name.trim_start_matches("r#").trim_end_matches('_');
+        let column_name = &column.column_name;
+        let ty = &column.ty;
+
+        lines.push(format!(
+            "    /// Filter column `{column_name}` to be equal to the value\n    pub {field_name}: Option<{ty}>,"
+        ));
+        if column.is_nullable {
+            lines.push(format!(
+                "    /// Filter column `{column_name}` to be `NULL` (`true`) or not `NULL` (`false`)\n    pub {field_suffix}_is_null: Option<bool>,"
+            ));
+        }
+        match kind {
+            FilterKind::Equal => (),
+            FilterKind::Range => {
+                lines.push(format!(
+                    "    /// Filter column `{column_name}` to be greater than or equal to the value\n    pub {field_suffix}_gte: Option<{ty}>,"
+                ));
+                lines.push(format!(
+                    "    /// Filter column `{column_name}` to be less than or equal to the value\n    pub {field_suffix}_lte: Option<{ty}>,"
+                ));
+            }
+            FilterKind::Like => {
+                lines.push(format!(
+                    "    /// Filter column `{column_name}` to match the `LIKE` pattern\n    pub {field_suffix}_like: Option<String>,"
+                ));
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        return String::new();
+    }
+
+    #[cfg(feature = "tsync")]
+    let tsync = match table_options.get_tsync() {
+        true => "#[tsync::tsync]\n",
+        false => "",
+    };
+    #[cfg(not(feature = "tsync"))]
+    let tsync = "";
+
+    let serde_derive = if table_options.get_serde() {
+        ", Serialize, Deserialize"
+    } else {
+        ""
+    };
+
+    format!(
+        "/// Filter for [`{read_struct_name}`] on table `{table_name}`, only rows matching all set fields are selected
+{tsync}#[derive(Debug, Clone, Default{serde_derive})]
+pub struct {filter_struct_name} {{
+{lines}
+}}\n",
+        read_struct_name = StructType::Read.format(&table.struct_name, config),
+        table_name = table.name,
+        filter_struct_name = filter_struct_name(table, config),
+        lines = lines.join("\n"),
+    )
+}
+
+/// Generate the `filter` function, which builds a boxed query from the set fields of the "*Filter" struct
+///
+/// Returns a empty string if there is no column which can be filtered by
+fn build_filter_fn(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    let table_options = config.table(&table.name.to_string());
+    let table_path = format!("{}{}", table.schema_path(config), table.name);
+    let mut conditions = vec![];
+
+    for (column, kind) in filter_columns(table, &table_options) {
+        let field_name = column.name.to_string();
+        let field_suffix = field_name.trim_start_matches("r#").trim_end_matches('_');
+        let column_path = format!("{table_path}::{field_name}");
+
+        conditions.push(format!(
+            "            if let Some(value) = &filter.{field_name} {{
+                query = query.filter({column_path}.eq(value));
+            }}"
+        ));
+        if column.is_nullable {
+            conditions.push(format!(
+                "            if let Some(is_null) = filter.{field_suffix}_is_null {{
+                query = if is_null {{ query.filter({column_path}.is_null()) }} else {{ query.filter({column_path}.is_not_null()) }};
+            }}"
+            ));
+        }
+        match kind {
+            FilterKind::Equal => (),
+            FilterKind::Range => {
+                conditions.push(format!(
+                    "            if let Some(value) = &filter.{field_suffix}_gte {{
+                query = query.filter({column_path}.ge(value));
+            }}
+            if let Some(value) = &filter.{field_suffix}_lte {{
+                query = query.filter({column_path}.le(value));
+            }}"
+                ));
+            }
+            FilterKind::Like => {
+                conditions.push(format!(
+                    "            if let Some(value) = &filter.{field_suffix}_like {{
+                query = query.filter({column_path}.like(value));
+            }}"
+                ));
+            }
+        }
+    }
+
+    if conditions.is_empty() {
+        return String::new();
+    }
+
+    #[cfg(feature = "async")]
+    let (async_keyword, await_keyword) = if table_options.get_async() {
+        (" async", ".await")
+    } else {
+        ("", "")
+    };
+    #[cfg(not(feature = "async"))]
+    let (async_keyword, await_keyword) = ("", "");
+
+    // the main struct does not have all columns if any are excluded, so only its columns can be selected
+    let select_self = if table.columns.iter().any(|column| {
+        table_options
+            .get_exclude_columns()
+            .contains(&column.name.to_string().as_str())
+    }) {
+        ".select(Self::as_select())"
+    } else {
+        ""
+    };
+    let connection_type_path = get_connection_type_path(config);
+    let pagination_result_path = if config.lessen_conflicts {
+        "common::PaginationResult"
+    } else {
+        "PaginationResult"
+    };
+    let filter_struct_name = filter_struct_name(table, config);
+    let conditions = conditions.join("\n");
+    let primary_key_order = primary_key_then_order(table, &table_path);
+
+    format!(
+        r##"
+    /// Paginates through the rows matching all set fields of the given [`{filter_struct_name}`] ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub{async_keyword} fn filter(db: &mut {connection_type_path}, filter: &{filter_struct_name}, page: i64, page_size: i64) -> QueryResult<{pagination_result_path}<Self>> {{
+        // a boxed query can only be used once, so it is built for both the count and the items
+        let query = || {{
+            let mut query = {table_path}::table.into_boxed();
+{conditions}
+            query
+        }};
+
+        let page_size = if page_size < 1 {{ 1 }} else {{ page_size }};
+        let total_items = query().count().get_result(db){await_keyword}?;
+        let items = query(){primary_key_order}{select_self}.limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
+
+        Ok({pagination_result_path} {{
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        }})
+    }}
+"##
+    )
+}
+
+/// Get the ordering by all primary key columns for a boxed query, like ".then_order_by(crate::schema::users::id.asc())"
+fn primary_key_then_order(table: &ParsedTableMacro, table_path: &str) -> String {
+    table
+        .primary_key_columns
+        .iter()
+        .map(|column| format!(".then_order_by({table_path}::{column}.asc())"))
+        .collect()
+}
+
+/// Get the name of the "*OrderBy" enum for a table
+fn order_by_enum_name(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    format!(
+        "{}OrderBy",
+        StructType::Read.format(&table.struct_name, config)
+    )
+}
+
+/// Get whether a column can be ordered by, which is not the case for `Record`, `Array` and `Json` columns
+fn is_orderable(column: &ParsedColumnMacro) -> bool {
+    match column.sql_type.strip_nullable() {
+        ParsedColumnType::Base(ident) => {
+            !matches!(ident.to_string().to_lowercase().as_str(), "json" | "jsonb")
+        }
+        ParsedColumnType::Unsigned(_) => true,
+        ParsedColumnType::Array(_)
+        | ParsedColumnType::Record(_)
+        | ParsedColumnType::Nullable(_) => false,
+    }
+}
+
+/// Get the variant names of the "*OrderBy" enum with their column, for all columns of the main struct which can be ordered by
+fn order_by_variants<'a>(
+    table: &'a ParsedTableMacro,
+    table_options: &TableOptions,
+) -> Vec<(String, &'a ParsedColumnMacro)> {
+    table
+        .columns
+        .iter()
+        .filter(|column| {
+            !table_options
+                .get_exclude_columns()
+                .contains(&column.name.to_string().as_str())
+                && is_orderable(column)
+        })
+        .map(|column| {
+            let variant = column
+                .name
+                .to_string()
+                .trim_start_matches("r#")
+                .to_pascal_case();
+
+            (variant, column)
+        })
+        .collect()
+}
+
+/// Generate the "*OrderBy" enum, which has a variant with a [OrderDirection] for each column which can be ordered by
+///
+/// Returns a empty string if there is no column which can be ordered by
+fn build_order_by_enum(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    let table_options = config.table(&table.name.to_string());
+    let order_direction_path = if config.lessen_conflicts {
+        "common::OrderDirection"
+    } else {
+        "OrderDirection"
+    };
+
+    let variants = order_by_variants(table, &table_options)
+        .into_iter()
+        .map(|(variant, column)| {
+            format!(
+                "    /// Order by column `{column_name}`\n    {variant}({order_direction_path}),",
+                column_name = column.column_name
+            )
+        })
+        .collect::<Vec<String>>();
+
+    if variants.is_empty() {
+        return String::new();
+    }
+
+    #[cfg(feature = "tsync")]
+    let tsync = match table_options.get_tsync() {
+        true => "#[tsync::tsync]\n",
+        false => "",
+    };
+    #[cfg(not(feature = "tsync"))]
+    let tsync = "";
+
+    let serde_derive = if table_options.get_serde() {
+        ", Serialize, Deserialize"
+    } else {
+        ""
+    };
+
+    format!(
+        "/// Column to order [`{read_struct_name}`] by in `paginate_ordered`, with the direction
+{tsync}#[derive(Debug, Clone, Copy, PartialEq, Eq{serde_derive})]
+pub enum {order_by_enum_name} {{
+{variants}
+}}\n",
+        read_struct_name = StructType::Read.format(&table.struct_name, config),
+        order_by_enum_name = order_by_enum_name(table, config),
+        variants = variants.join("\n"),
+    )
+}
+
+/// Generate the `paginate_ordered` function, which orders by the "*OrderBy" enum and then by the primary key
+///
+/// Returns a empty string if there is no column which can be ordered by
+fn build_paginate_ordered_fn(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
+    let table_options = config.table(&table.name.to_string());
+    let table_path = format!("{}{}", table.schema_path(config), table.name);
+    let order_by_enum_name = order_by_enum_name(table, config);
+    let order_direction_path = if config.lessen_conflicts {
+        "common::OrderDirection"
+    } else {
+        "OrderDirection"
+    };
+
+    let arms = order_by_variants(table, &table_options)
+        .into_iter()
+        .map(|(variant, column)| {
+            let column_path = format!("{table_path}::{}", column.name);
+
+            format!(
+                "                {order_by_enum_name}::{variant}({order_direction_path}::Asc) => query.then_order_by({column_path}.asc()),
+                {order_by_enum_name}::{variant}({order_direction_path}::Desc) => query.then_order_by({column_path}.desc()),"
+            )
+        })
+        .collect::<Vec<String>>();
+
+    if arms.is_empty() {
+        return String::new();
+    }
+
+    #[cfg(feature = "async")]
+    let (async_keyword, await_keyword) = if table_options.get_async() {
+        (" async", ".await")
+    } else {
+        ("", "")
+    };
+    #[cfg(not(feature = "async"))]
+    let (async_keyword, await_keyword) = ("", "");
+
+    // the main struct does not have all columns if any are excluded, so only its columns can be selected
+    let select_self = if table.columns.iter().any(|column| {
+        table_options
+            .get_exclude_columns()
+            .contains(&column.name.to_string().as_str())
+    }) {
+        ".select(Self::as_select())"
+    } else {
+        ""
+    };
+    let connection_type_path = get_connection_type_path(config);
+    let pagination_result_path = if config.lessen_conflicts {
+        "common::PaginationResult"
+    } else {
+        "PaginationResult"
+    };
+    let primary_key_order = primary_key_then_order(table, &table_path);
+    let arms = arms.join("\n");
+
+    format!(
+        r##"
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub{async_keyword} fn paginate_ordered(db: &mut {connection_type_path}, page: i64, page_size: i64, order_by: &[{order_by_enum_name}]) -> QueryResult<{pagination_result_path}<Self>> {{
+        let page_size = if page_size < 1 {{ 1 }} else {{ page_size }};
+        let total_items = {table_path}::table.count().get_result(db){await_keyword}?;
+
+        let mut query = {table_path}::table.into_boxed();
+        for order in order_by {{
+            query = match order {{
+{arms}
+            }};
+        }}
+        let items = query{primary_key_order}{select_self}.limit(page_size).offset(page * page_size).load::<Self>(db){await_keyword}?;
+
+        Ok({pagination_result_path} {{
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        }})
+    }}
+"##
+    )
+}
+
 /// Generate common structs
 pub fn generate_common_structs(table_options: &TableOptions<'_>) -> String {
     #[cfg(feature = "tsync")]
@@ -561,41 +1374,367 @@ pub struct PaginationResult<T> {{
     /// Number of pages in total
     pub num_pages: i64,
 }}
+
+/// Direction to order by, used by the `*OrderBy` enums
+{tsync}#[derive(Debug, Clone, Copy, PartialEq, Eq{order_serde_derive})]
+pub enum OrderDirection {{
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}}
 "##,
         serde_derive = if table_options.get_serde() {
             Struct::DERIVE_Serde_Serialize
         } else {
             ""
+        },
+        order_serde_derive = if table_options.get_serde() {
+            ", Serialize, Deserialize"
+        } else {
+            ""
         }
     )
 }
 
+/// Generate the `Range<T>` type for postgres range types, including the `FromSql` and `ToSql` implementations
+pub fn generate_range_type(use_serde: bool) -> String {
+    let serde_derive = if use_serde {
+        ", serde::Serialize, serde::Deserialize"
+    } else {
+        ""
+    };
+
+    format!(
+        r##"/// A postgres range, with [None] as a unbounded side
+/// Converts to and from the diesel representation `(Bound<T>, Bound<T>)`
+#[derive(Debug, Clone, PartialEq, Eq{serde_derive}, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = diesel::sql_types::Int4range)]
+#[diesel(sql_type = diesel::sql_types::Int8range)]
+#[diesel(sql_type = diesel::sql_types::Numrange)]
+#[diesel(sql_type = diesel::sql_types::Daterange)]
+#[diesel(sql_type = diesel::sql_types::Tsrange)]
+#[diesel(sql_type = diesel::sql_types::Tstzrange)]
+pub struct Range<T> {{
+    /// Start of the range, [None] if unbounded
+    pub start: Option<T>,
+    /// End of the range, [None] if unbounded
+    pub end: Option<T>,
+    /// Whether `start` is part of the range
+    pub start_inclusive: bool,
+    /// Whether `end` is part of the range
+    pub end_inclusive: bool,
+}}
+
+impl<T> From<(std::ops::Bound<T>, std::ops::Bound<T>)> for Range<T> {{
+    fn from((start, end): (std::ops::Bound<T>, std::ops::Bound<T>)) -> Self {{
+        use std::ops::Bound;
+
+        let (start, start_inclusive) = match start {{
+            Bound::Included(value) => (Some(value), true),
+            Bound::Excluded(value) => (Some(value), false),
+            Bound::Unbounded => (None, false),
+        }};
+        let (end, end_inclusive) = match end {{
+            Bound::Included(value) => (Some(value), true),
+            Bound::Excluded(value) => (Some(value), false),
+            Bound::Unbounded => (None, false),
+        }};
+
+        Self {{
+            start,
+            end,
+            start_inclusive,
+            end_inclusive,
+        }}
+    }}
+}}
+
+impl<T> From<Range<T>> for (std::ops::Bound<T>, std::ops::Bound<T>) {{
+    fn from(range: Range<T>) -> Self {{
+        use std::ops::Bound;
+
+        let start = match range.start {{
+            Some(value) if range.start_inclusive => Bound::Included(value),
+            Some(value) => Bound::Excluded(value),
+            None => Bound::Unbounded,
+        }};
+        let end = match range.end {{
+            Some(value) if range.end_inclusive => Bound::Included(value),
+            Some(value) => Bound::Excluded(value),
+            None => Bound::Unbounded,
+        }};
+
+        (start, end)
+    }}
+}}
+
+impl<T, ST> diesel::deserialize::FromSql<diesel::sql_types::Range<ST>, diesel::pg::Pg> for Range<T>
+where
+    (std::ops::Bound<T>, std::ops::Bound<T>): diesel::deserialize::FromSql<diesel::sql_types::Range<ST>, diesel::pg::Pg>,
+{{
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {{
+        let bounds: (std::ops::Bound<T>, std::ops::Bound<T>) =
+            diesel::deserialize::FromSql::<diesel::sql_types::Range<ST>, diesel::pg::Pg>::from_sql(bytes)?;
+
+        Ok(bounds.into())
+    }}
+}}
+
+impl<T, ST> diesel::serialize::ToSql<diesel::sql_types::Range<ST>, diesel::pg::Pg> for Range<T>
+where
+    T: Clone + std::fmt::Debug,
+    (std::ops::Bound<T>, std::ops::Bound<T>): diesel::serialize::ToSql<diesel::sql_types::Range<ST>, diesel::pg::Pg>,
+{{
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {{
+        let bounds: (std::ops::Bound<T>, std::ops::Bound<T>) = self.clone().into();
+
+        diesel::serialize::ToSql::<diesel::sql_types::Range<ST>, diesel::pg::Pg>::to_sql(&bounds, &mut out.reborrow())
+    }}
+}}
+"##
+    )
+}
+
+/// Generate the rust types for all custom sql types
+pub fn generate_sql_types(
+    sql_types: &[ParsedSqlType],
+    tables: &[ParsedTableMacro],
+    config: &GenerationConfig,
+) -> String {
+    let mut buffer = format!("{FILE_SIGNATURE}\n\n");
+
+    let use_serde = sql_types.iter().any(|sql_type| {
+        sql_type_table_options(sql_type, tables, config)
+            .iter()
+            .any(TableOptions::get_serde)
+    });
+
+    if use_serde {
+        buffer.push_str("use serde::{Deserialize, Serialize};\n\n");
+    }
+
+    let code = sql_types
+        .iter()
+        .map(|sql_type| {
+            generate_sql_type(
+                sql_type,
+                &sql_type_table_options(sql_type, tables, config),
+                config,
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    buffer.push_str(&code);
+
+    buffer
+}
+
+/// Get the options of all tables with a column of `sql_type`, or the default table options if no table uses it
+fn sql_type_table_options<'a>(
+    sql_type: &ParsedSqlType,
+    tables: &[ParsedTableMacro],
+    config: &'a GenerationConfig,
+) -> Vec<TableOptions<'a>> {
+    let table_options: Vec<TableOptions> = tables
+        .iter()
+        .filter(|table| {
+            table.schema == sql_type.schema
+                && table
+                    .columns
+                    .iter()
+                    .filter(|column| !column.is_type_overridden)
+                    .any(|column| column.sql_type.base_idents().contains(&&sql_type.name))
+        })
+        .map(|table| config.table(&table.name.to_string()))
+        .collect();
+
+    if table_options.is_empty() {
+        vec![config.default_table_options.clone()]
+    } else {
+        table_options
+    }
+}
+
+/// Generate the rust type for a single custom sql type, including the `FromSql` and `ToSql` implementations
+/// serde and tsync are used if any table in `table_options` (the tables using the type) uses them
+fn generate_sql_type(
+    sql_type: &ParsedSqlType,
+    table_options: &[TableOptions],
+    config: &GenerationConfig,
+) -> String {
+    let name = &sql_type.name;
+    let sql_type_path = format!(
+        "{}sql_types::{name}",
+        path_with_schema(&config.schema_path, sql_type.schema.as_ref())
+    );
+    let backend = sql_type.backend.backend_path();
+    let value = sql_type.backend.value_path();
+    let use_serde = table_options.iter().any(TableOptions::get_serde);
+    let db_name = sql_type.db_name.as_deref().unwrap_or_default();
+    let db_name_doc = if db_name.is_empty() {
+        String::new()
+    } else {
+        format!(" (`{db_name}`)")
+    };
+
+    let variants = sql_type
+        .db_name
+        .as_deref()
+        .and_then(|v| config.sql_type_variants.get(v))
+        .or_else(|| config.sql_type_variants.get(name.to_string().as_str()));
+
+    let Some(variants) = variants else {
+        // no variants are known, so generate a wrapper around the raw string
+        let serde_derive = if use_serde {
+            ", Serialize, Deserialize"
+        } else {
+            ""
+        };
+
+        return format!(
+            r##"/// Rust type for sql type [`{sql_type_path}`]{db_name_doc}
+#[derive(Debug, Clone, PartialEq, Eq{serde_derive}, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = {sql_type_path})]
+pub struct {name}(pub String);
+
+impl diesel::deserialize::FromSql<{sql_type_path}, {backend}> for {name} {{
+    fn from_sql(bytes: {value}<'_>) -> diesel::deserialize::Result<Self> {{
+        Ok(Self(String::from_utf8(bytes.as_bytes().to_vec())?))
+    }}
+}}
+
+impl diesel::serialize::ToSql<{sql_type_path}, {backend}> for {name} {{
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, {backend}>) -> diesel::serialize::Result {{
+        use std::io::Write;
+
+        out.write_all(self.0.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }}
+}}
+"##
+        );
+    };
+
+    #[cfg(feature = "tsync")]
+    let tsync = match table_options.iter().any(TableOptions::get_tsync) {
+        true => "#[tsync::tsync]\n",
+        false => "",
+    };
+    #[cfg(not(feature = "tsync"))]
+    let tsync = "";
+
+    let serde_derive = if use_serde {
+        ", Serialize, Deserialize"
+    } else {
+        ""
+    };
+
+    let variants: Vec<(String, &str)> = variants.iter().map(|v| (v.to_pascal_case(), *v)).collect();
+
+    let enum_variants = variants
+        .iter()
+        .map(|(variant, db_variant)| {
+            let serde_rename = if use_serde && variant != db_variant {
+                format!("    #[serde(rename = \"{db_variant}\")]\n")
+            } else {
+                String::new()
+            };
+
+            format!("    /// Variant representing `{db_variant}`\n{serde_rename}    {variant},")
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let from_sql_arms = variants
+        .iter()
+        .map(|(variant, db_variant)| {
+            format!("            b\"{db_variant}\" => Ok(Self::{variant}),")
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let to_sql_arms = variants
+        .iter()
+        .map(|(variant, db_variant)| format!("            Self::{variant} => \"{db_variant}\","))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!(
+        r##"/// Rust type for sql type [`{sql_type_path}`]{db_name_doc}
+{tsync}#[derive(Debug, Clone, Copy, PartialEq, Eq{serde_derive}, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = {sql_type_path})]
+pub enum {name} {{
+{enum_variants}
+}}
+
+impl diesel::deserialize::FromSql<{sql_type_path}, {backend}> for {name} {{
+    fn from_sql(bytes: {value}<'_>) -> diesel::deserialize::Result<Self> {{
+        match bytes.as_bytes() {{
+{from_sql_arms}
+            v => Err(format!("Unknown variant for \"{name}\": \"{{}}\"", String::from_utf8_lossy(v)).into()),
+        }}
+    }}
+}}
+
+impl diesel::serialize::ToSql<{sql_type_path}, {backend}> for {name} {{
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, {backend}>) -> diesel::serialize::Result {{
+        use std::io::Write;
+
+        let value = match self {{
+{to_sql_arms}
+        }};
+
+        out.write_all(value.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }}
+}}
+"##
+    )
+}
+
 /// Generate all the imports that are required
 fn build_imports(table: &ParsedTableMacro, config: &GenerationConfig) -> String {
     let mut imports_buffer = String::from("use crate::diesel::*;\n");
 
-    #[cfg(feature = "async")]
     let table_options = config.table(&table.name.to_string());
-    for belong in table.foreign_keys.iter().map(|fk| {
-        format!(
-            "use {model_path}{foreign_table_name_model}::{singular_struct_name};\n",
-            foreign_table_name_model = fk.0.to_string().to_snake_case().to_lowercase(),
-            singular_struct_name = fk.0.to_string().to_pascal_case(),
-            model_path = config.model_path
-        )
-    }) {
-        imports_buffer.push_str(&belong);
+    let mut model_tables: Vec<&syn::Ident> = table.foreign_keys.iter().map(|fk| &fk.0).collect();
+    // children and many-to-many relations are only used in the functions
+    if table_options.get_generate_impls() {
+        model_tables.extend(table.children.iter().map(|child| &child.0));
+        model_tables.extend(
+            table
+                .many_to_many
+                .iter()
+                .map(|relation| &relation.other_table),
+        );
+    }
+    let mut model_imports: Vec<String> = vec![];
+    for model_table_name in model_tables {
+        let model_import = format!(
+            "use {model_path}{model_table_name_model}::{model_struct_name};\n",
+            model_table_name_model = model_table_name.to_string().to_snake_case().to_lowercase(),
+            model_struct_name = read_struct_name(&model_table_name.to_string(), config),
+            model_path = table.model_path(config)
+        );
+        // a table may be referenced multiple times, like as parent and as child
+        if !model_imports.contains(&model_import) {
+            imports_buffer.push_str(&model_import);
+            model_imports.push(model_import);
+        }
     }
     #[cfg(feature = "async")]
     if table_options.get_async() {
         imports_buffer.push_str("use diesel_async::RunQueryDsl;\n");
     };
-    if config.default_table_options.get_serde() {
+    if table_options.get_serde() {
         imports_buffer.push_str("use serde::{Deserialize, Serialize};\n");
     };
     // dont generate "common" import if no "impl" is generated, because it is a useless import then
-    if (config.once_common_structs || config.once_connection)
-        && config.default_table_options.get_generate_impls()
+    if (config.once_common_structs || config.once_connection) && table_options.get_generate_impls()
     {
         if config.lessen_conflicts {
             imports_buffer.push_str(&format!("use {}common;\n", config.model_path));
@@ -604,7 +1743,7 @@ fn build_imports(table: &ParsedTableMacro, config: &GenerationConfig) -> String
         }
     };
 
-    imports_buffer.push_str(&format!("use {}*;\n", config.schema_path));
+    imports_buffer.push_str(&format!("use {}*;\n", table.schema_path(config)));
 
     if !config.once_connection {
         imports_buffer.push_str(&format!(
@@ -633,13 +1772,40 @@ pub fn generate_for_table(table: ParsedTableMacro, config: &GenerationConfig) ->
         structs.push('\n');
         structs.push_str(update_struct.code());
     }
+    if config.table(&table.name.to_string()).get_generate_impls() {
+        let order_by_enum = build_order_by_enum(&table, config);
+        if !order_by_enum.is_empty() {
+            structs.push('\n');
+            structs.push_str(&order_by_enum);
+        }
+    }
+    if config.table(&table.name.to_string()).get_filter_struct() {
+        let filter_struct = build_filter_struct(&table, config);
+        if !filter_struct.is_empty() {
+            structs.push('\n');
+            structs.push_str(&filter_struct);
+        }
+    }
 
     let functions = build_table_fns(&table, config, create_struct, update_struct)?;
     let imports = build_imports(&table, config);
 
-    Ok(format!(
-        "{FILE_SIGNATURE}\n\n{imports}\n{structs}\n{functions}\n"
-    ))
+    // note the required diesel feature, because it is not obvious from the diesel schema
+    let header = if table.uses_datetime_types(config) {
+        format!(
+            "{FILE_SIGNATURE}\n/* Date and time types require diesel feature \"{}\" */",
+            config.datetime_backend.diesel_feature()
+        )
+    } else {
+        FILE_SIGNATURE.to_string()
+    };
+
+    Ok(format!("{header}\n\n{imports}\n{structs}\n{functions}\n"))
+}
+
+/// Get the name of the main struct for a table, like for the table of a foreign key
+fn read_struct_name(table_name: &str, config: &GenerationConfig) -> String {
+    StructType::Read.format(&config.struct_name(table_name), config)
 }
 
 /// Get the path to the connection type, assuming "common" is imported
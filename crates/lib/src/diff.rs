@@ -0,0 +1,42 @@
+use similar::TextDiff;
+use std::path::Path;
+
+/// Contents of a file before and after generation, [None] meaning that the file does not exist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// Contents currently on disk
+    pub old: Option<String>,
+    /// Contents after generation
+    pub new: Option<String>,
+}
+
+impl FileDiff {
+    /// Render the changes as a unified diff for the given path
+    /// the headers use `/dev/null` for a created or deleted file, so it can be applied with `patch -p0`
+    /// returns a empty string if there are no changes
+    pub fn unified_diff(&self, path: &Path) -> String {
+        if self.old == self.new {
+            return String::new();
+        }
+
+        let path = path.to_string_lossy();
+        let old_header = if self.old.is_some() {
+            path.as_ref()
+        } else {
+            "/dev/null"
+        };
+        let new_header = if self.new.is_some() {
+            path.as_ref()
+        } else {
+            "/dev/null"
+        };
+
+        TextDiff::from_lines(
+            self.old.as_deref().unwrap_or_default(),
+            self.new.as_deref().unwrap_or_default(),
+        )
+        .unified_diff()
+        .header(old_header, new_header)
+        .to_string()
+    }
+}
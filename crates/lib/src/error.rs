@@ -2,7 +2,11 @@
 // error_generic_member_access https://github.com/rust-lang/rust/issues/99301
 // provide_any https://github.com/rust-lang/rust/issues/96024
 
-use std::{backtrace::Backtrace, io::Error as ioError, path::Path};
+use std::{
+    backtrace::Backtrace,
+    io::Error as ioError,
+    path::{Path, PathBuf},
+};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -14,7 +18,7 @@ macro_rules! fn_string {
         where
             M: Into<String>,
         {
-            return Self::new($fortype(msg.into()));
+            Self::new($fortype(msg.into()))
         }
     };
 }
@@ -27,6 +31,8 @@ pub struct Error {
     #[cfg(feature = "backtrace")]
     /// The backtrace for the error
     backtrace: Backtrace,
+    /// The location in the source (schema) file the error is for, if any
+    diagnostic: Option<Box<Diagnostic>>,
 }
 
 impl Error {
@@ -36,6 +42,7 @@ impl Error {
             source,
             #[cfg(feature = "backtrace")]
             backtrace: Backtrace::capture(),
+            diagnostic: None,
         }
     }
 
@@ -45,6 +52,42 @@ impl Error {
         &self.backtrace
     }
 
+    /// Get the location in the source file the error is for, if any
+    pub fn get_diagnostic(&self) -> Option<&Diagnostic> {
+        self.diagnostic.as_deref()
+    }
+
+    /// Attach the location of `span` to this [Error]
+    /// does nothing if there is already a location attached, so that the innermost location is kept
+    pub fn with_span(mut self, span: proc_macro2::Span) -> Self {
+        if self.diagnostic.is_none() {
+            self.diagnostic = Some(Box::new(Diagnostic::from_span(span)));
+        }
+
+        self
+    }
+
+    /// Attach the source snippet from `source` to the already attached location, if any
+    pub fn with_source(mut self, source: &str) -> Self {
+        if let Some(diagnostic) = self.diagnostic.as_mut() {
+            diagnostic.snippet = source
+                .lines()
+                .nth(diagnostic.line.saturating_sub(1))
+                .map(|v| v.to_owned());
+        }
+
+        self
+    }
+
+    /// Attach the path of the source file to the already attached location, if any
+    pub fn with_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        if let Some(diagnostic) = self.diagnostic.as_mut() {
+            diagnostic.path = Some(path.as_ref().to_owned());
+        }
+
+        self
+    }
+
     fn_string!(other, ErrorEnum::Other);
     fn_string!(
         unsupported_schema_format,
@@ -59,10 +102,10 @@ impl Error {
         M: Into<String>,
         P: AsRef<Path>,
     {
-        return Self::new(ErrorEnum::IoError(
+        Self::new(ErrorEnum::IoError(
             ioError::new(kind, msg.into()),
             format_path(path.as_ref().to_string_lossy().to_string()),
-        ));
+        ))
     }
 
     pub fn not_a_directory<M, P>(msg: M, path: P) -> Self
@@ -70,10 +113,10 @@ impl Error {
         M: Into<String>,
         P: AsRef<Path>,
     {
-        return Self::new(ErrorEnum::NotADirectory(
+        Self::new(ErrorEnum::NotADirectory(
             msg.into(),
             path.as_ref().to_string_lossy().to_string(),
-        ));
+        ))
     }
 }
 
@@ -85,7 +128,7 @@ impl std::fmt::Display for Error {
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        return self.source.source();
+        self.source.source()
     }
 }
 
@@ -119,12 +162,94 @@ pub enum ErrorEnum {
     /// Variant for when "has_file_signature" is `false`
     #[error("NoFileSignature: {0}")]
     NoFileSignature(String),
+    /// Wrapper Variant for errors while reading a sqlite database
+    #[cfg(feature = "sqlite")]
+    #[error("SqliteError: {0}")]
+    SqliteError(#[from] rusqlite::Error),
 
     /// Variant for Other messages
     #[error("Other: {0}")]
     Other(String),
 }
 
+/// Location in a source (schema) file a [Error] is for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Path of the source file, if known
+    pub path: Option<PathBuf>,
+    /// Start line of the span, 1-based
+    pub line: usize,
+    /// Start column of the span, 0-based
+    pub column: usize,
+    /// End line of the span, 1-based
+    pub end_line: usize,
+    /// End column of the span, 0-based
+    pub end_column: usize,
+    /// The source line the span starts on, if the source is known
+    pub snippet: Option<String>,
+}
+
+impl Diagnostic {
+    /// Create a new [Diagnostic] from the location of `span`
+    pub fn from_span(span: proc_macro2::Span) -> Self {
+        let start = span.start();
+        let end = span.end();
+
+        Self {
+            path: None,
+            line: start.line,
+            column: start.column,
+            end_line: end.line,
+            end_column: end.column,
+            snippet: None,
+        }
+    }
+}
+
+/// Display the location like rustc does
+/// ```txt
+///   --> schema.rs:4:17
+///    |
+///  4 |         tags -> Array<Foo, Bar>,
+///    |                 ^^^^^^^^^^^^^^^
+/// ```
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line_number = self.line.to_string();
+        let padding = " ".repeat(line_number.len());
+        let path = self
+            .path
+            .as_ref()
+            .map(|v| v.to_string_lossy().to_string())
+            .unwrap_or_else(|| String::from("<schema>"));
+
+        write!(
+            f,
+            "{padding}--> {path}:{line}:{column}",
+            line = self.line,
+            column = self.column + 1
+        )?;
+
+        let Some(snippet) = &self.snippet else {
+            return Ok(());
+        };
+
+        // underline until the end of the line if the span is over multiple lines
+        let end_column = if self.end_line == self.line {
+            self.end_column
+        } else {
+            snippet.chars().count()
+        };
+        let underline = "^".repeat(end_column.saturating_sub(self.column).max(1));
+
+        write!(
+            f,
+            "\n{padding} |\n{line_number} | {snippet}\n{padding} | {indent}{underline}",
+            indent = " ".repeat(self.column)
+        )
+    }
+}
+
 /// Helper function to keep consistent formatting
 #[inline]
 fn format_path(msg: String) -> String {
@@ -142,13 +267,13 @@ pub trait IOErrorToError<T> {
 
 impl<T> IOErrorToError<T> for std::result::Result<T, std::io::Error> {
     fn attach_path_err<P: AsRef<Path>>(self, path: P) -> Result<T> {
-        return match self {
+        match self {
             Ok(v) => Ok(v),
             Err(e) => Err(crate::Error::new(ErrorEnum::IoError(
                 e,
                 format_path(path.as_ref().to_string_lossy().to_string()),
             ))),
-        };
+        }
     }
 
     fn attach_path_msg<P: AsRef<Path>, M: AsRef<str>>(self, path: P, msg: M) -> Result<T> {
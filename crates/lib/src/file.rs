@@ -2,29 +2,38 @@ use std::path::{Path, PathBuf};
 
 use crate::{
     error::{Error, IOErrorToError, Result},
-    FILE_SIGNATURE,
+    FileDiff, FILE_SIGNATURE,
 };
 
 #[derive(Debug)]
 pub struct MarkedFile {
     /// File contents to read / to write
     file_contents: String,
+    /// File contents as read from disk
+    original_contents: String,
     /// Path of the resulting file
     pub path: PathBuf,
     modified: bool,
+    /// Whether the file did not exist when opened
+    new: bool,
 }
 
 impl MarkedFile {
     /// Tries to open the file at `path` and read contents into `file_contents`
     pub fn new(path: PathBuf) -> Result<MarkedFile> {
+        let new = !path.exists();
+        let file_contents = if new {
+            "".to_owned()
+        } else {
+            std::fs::read_to_string(&path).attach_path_err(&path)?
+        };
+
         Ok(MarkedFile {
-            path: path.clone(),
-            file_contents: if !path.exists() {
-                "".to_owned()
-            } else {
-                std::fs::read_to_string(&path).attach_path_err(&path)?
-            },
+            path,
+            original_contents: file_contents.clone(),
+            file_contents,
             modified: false,
+            new,
         })
     }
 
@@ -36,6 +45,27 @@ impl MarkedFile {
         self.modified
     }
 
+    /// Get whether the file did not exist before
+    pub fn is_new(&self) -> bool {
+        self.new
+    }
+
+    /// Get the changes from the contents on disk to the current contents
+    pub fn diff(&self) -> FileDiff {
+        FileDiff {
+            old: (!self.new).then(|| self.original_contents.clone()),
+            new: Some(self.file_contents.clone()),
+        }
+    }
+
+    /// Get the changes from the contents on disk to the file being deleted
+    pub fn deleted_diff(&self) -> FileDiff {
+        FileDiff {
+            old: Some(self.original_contents.clone()),
+            new: None,
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.file_contents.is_empty() || self.file_contents.trim() == FILE_SIGNATURE
     }
@@ -56,11 +86,6 @@ impl MarkedFile {
         self.file_contents = new_content;
     }
 
-    /// Change `file_contents` to be `new_content` always, does not set `modified`
-    pub fn change_file_contents_no_modify(&mut self, new_content: String) {
-        self.file_contents = new_content;
-    }
-
     pub fn add_use_stmt(&mut self, use_name: &str) {
         self.file_contents = self.file_contents.trim().to_string();
         if !self.file_contents.is_empty() {
@@ -1,18 +1,27 @@
 mod code;
+mod diff;
 mod error;
 mod file;
+mod migrations;
+mod naming;
 mod parser;
+mod sqlite;
+mod warning;
 
 use code::get_connection_type_name;
+pub use diff::FileDiff;
 use error::IOErrorToError;
-pub use error::{Error, Result};
+pub use error::{Diagnostic, Error, Result};
+use heck::ToPascalCase;
 
 use file::MarkedFile;
-use parser::ParsedTableMacro;
-pub use parser::FILE_SIGNATURE;
+use parser::ParsedSchema;
+pub use parser::{AutogeneratedReason, FILE_SIGNATURE};
 use std::collections::HashMap;
 use std::fmt::Display;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use syn::Ident;
+pub use warning::Warning;
 
 /// Individual Options for a given table
 #[derive(Debug, Clone, Default)]
@@ -23,6 +32,12 @@ pub struct TableOptions<'a> {
     /// Names used for autogenerated columns which are NOT primary keys (for example: `created_at`, `updated_at`, etc.).
     autogenerated_columns: Option<Vec<&'a str>>,
 
+    /// Infer autogenerated columns from the schema, like serial types, sqlite rowid aliases and `autogenerated_patterns`
+    infer_autogenerated: Option<bool>,
+
+    /// Glob patterns (`*` and `?`) for column names to infer as autogenerated, like `*_at`
+    autogenerated_patterns: Option<Vec<&'a str>>,
+
     #[cfg(feature = "tsync")]
     /// Adds #[tsync] attribute to structs (see https://github.com/Wulf/tsync)
     tsync: Option<bool>,
@@ -45,6 +60,36 @@ pub struct TableOptions<'a> {
 
     /// Use "str" over "String" for "Create*" structs
     create_str_over_string: Option<bool>,
+
+    /// Columns to exclude from all generated structs, queries then only select the remaining columns
+    exclude_columns: Option<Vec<&'a str>>,
+
+    /// Columns to exclude from the "Create*" struct
+    create_exclude: Option<Vec<&'a str>>,
+
+    /// Columns to exclude from the "Update*" struct
+    update_exclude: Option<Vec<&'a str>>,
+
+    /// Columns which are never updated, excluded from the "Update*" struct and skipped in `AsChangeset` of the main struct
+    read_only_columns: Option<Vec<&'a str>>,
+
+    /// How the "Update*" struct sets columns
+    update_strategy: Option<UpdateStrategy>,
+
+    /// Use borrowed types over owned types for "Create*" and "Update*" structs
+    borrowed_types: Option<BorrowedTypes>,
+
+    /// Struct name to use instead of the one derived from the table name, the struct name templates still apply
+    struct_name: Option<&'a str>,
+
+    /// Unique columns to generate a `filter_by_*` function returning a single row for
+    unique_columns: Option<Vec<&'a str>>,
+
+    /// Indexed columns to generate `filter_by_*` and `paginate_by_*` functions for, like for foreign key columns
+    indexed_columns: Option<Vec<&'a str>>,
+
+    /// Generate a "*Filter" struct and a `filter` function to paginate through the rows matching it
+    filter_struct: Option<bool>,
 }
 
 impl<'a> TableOptions<'a> {
@@ -74,6 +119,14 @@ impl<'a> TableOptions<'a> {
         self.autogenerated_columns.as_deref().unwrap_or_default()
     }
 
+    pub fn get_infer_autogenerated(&self) -> bool {
+        self.infer_autogenerated.unwrap_or_default()
+    }
+
+    pub fn get_autogenerated_patterns(&self) -> &[&'_ str] {
+        self.autogenerated_patterns.as_deref().unwrap_or_default()
+    }
+
     pub fn get_read_only(&self) -> bool {
         self.read_only.unwrap_or_default()
     }
@@ -86,6 +139,46 @@ impl<'a> TableOptions<'a> {
         self.create_str_over_string.unwrap_or(false)
     }
 
+    pub fn get_exclude_columns(&self) -> &[&'_ str] {
+        self.exclude_columns.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_create_exclude(&self) -> &[&'_ str] {
+        self.create_exclude.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_update_exclude(&self) -> &[&'_ str] {
+        self.update_exclude.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_read_only_columns(&self) -> &[&'_ str] {
+        self.read_only_columns.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_update_strategy(&self) -> UpdateStrategy {
+        self.update_strategy.unwrap_or_default()
+    }
+
+    pub fn get_borrowed_types(&self) -> Option<BorrowedTypes> {
+        self.borrowed_types
+    }
+
+    pub fn get_struct_name(&self) -> Option<&'_ str> {
+        self.struct_name
+    }
+
+    pub fn get_unique_columns(&self) -> &[&'_ str] {
+        self.unique_columns.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_indexed_columns(&self) -> &[&'_ str] {
+        self.indexed_columns.as_deref().unwrap_or_default()
+    }
+
+    pub fn get_filter_struct(&self) -> bool {
+        self.filter_struct.unwrap_or_default()
+    }
+
     pub fn ignore(self) -> Self {
         Self {
             ignore: Some(true),
@@ -130,6 +223,20 @@ impl<'a> TableOptions<'a> {
         }
     }
 
+    pub fn infer_autogenerated(self) -> Self {
+        Self {
+            infer_autogenerated: Some(true),
+            ..self
+        }
+    }
+
+    pub fn autogenerated_patterns(self, patterns: Vec<&'a str>) -> Self {
+        Self {
+            autogenerated_patterns: Some(patterns),
+            ..self
+        }
+    }
+
     pub fn disable_impls(self) -> Self {
         Self {
             impls: Some(false),
@@ -144,10 +251,162 @@ impl<'a> TableOptions<'a> {
         }
     }
 
+    pub fn exclude_columns(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            exclude_columns: Some(cols),
+            ..self
+        }
+    }
+
+    pub fn create_exclude(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            create_exclude: Some(cols),
+            ..self
+        }
+    }
+
+    pub fn update_exclude(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            update_exclude: Some(cols),
+            ..self
+        }
+    }
+
+    pub fn read_only_columns(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            read_only_columns: Some(cols),
+            ..self
+        }
+    }
+
+    pub fn update_strategy(self, strategy: UpdateStrategy) -> Self {
+        Self {
+            update_strategy: Some(strategy),
+            ..self
+        }
+    }
+
+    pub fn borrowed_types(self, borrowed_types: BorrowedTypes) -> Self {
+        Self {
+            borrowed_types: Some(borrowed_types),
+            ..self
+        }
+    }
+
+    pub fn struct_name(self, name: &'a str) -> Self {
+        Self {
+            struct_name: Some(name),
+            ..self
+        }
+    }
+
+    pub fn unique_columns(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            unique_columns: Some(cols),
+            ..self
+        }
+    }
+
+    pub fn indexed_columns(self, cols: Vec<&'a str>) -> Self {
+        Self {
+            indexed_columns: Some(cols),
+            ..self
+        }
+    }
+
+    pub fn filter_struct(self) -> Self {
+        Self {
+            filter_struct: Some(true),
+            ..self
+        }
+    }
+
     pub fn set_read_only(&mut self, bool: bool) {
         self.read_only = Some(bool);
     }
 
+    pub fn set_ignore(&mut self, bool: bool) {
+        self.ignore = Some(bool);
+    }
+
+    #[cfg(feature = "tsync")]
+    pub fn set_tsync(&mut self, bool: bool) {
+        self.tsync = Some(bool);
+    }
+
+    #[cfg(feature = "async")]
+    pub fn set_async(&mut self, bool: bool) {
+        self.use_async = Some(bool);
+    }
+
+    pub fn set_serde(&mut self, bool: bool) {
+        self.use_serde = Some(bool);
+    }
+
+    pub fn set_only_necessary_derives(&mut self, bool: bool) {
+        self.only_necessary_derives = Some(bool);
+    }
+
+    pub fn set_autogenerated_columns(&mut self, cols: Vec<&'a str>) {
+        self.autogenerated_columns = Some(cols);
+    }
+
+    pub fn set_infer_autogenerated(&mut self, bool: bool) {
+        self.infer_autogenerated = Some(bool);
+    }
+
+    pub fn set_autogenerated_patterns(&mut self, patterns: Vec<&'a str>) {
+        self.autogenerated_patterns = Some(patterns);
+    }
+
+    pub fn set_generate_impls(&mut self, bool: bool) {
+        self.impls = Some(bool);
+    }
+
+    pub fn set_create_str(&mut self, bool: bool) {
+        self.create_str_over_string = Some(bool);
+    }
+
+    pub fn set_exclude_columns(&mut self, cols: Vec<&'a str>) {
+        self.exclude_columns = Some(cols);
+    }
+
+    pub fn set_create_exclude(&mut self, cols: Vec<&'a str>) {
+        self.create_exclude = Some(cols);
+    }
+
+    pub fn set_update_exclude(&mut self, cols: Vec<&'a str>) {
+        self.update_exclude = Some(cols);
+    }
+
+    pub fn set_read_only_columns(&mut self, cols: Vec<&'a str>) {
+        self.read_only_columns = Some(cols);
+    }
+
+    pub fn set_update_strategy(&mut self, strategy: UpdateStrategy) {
+        self.update_strategy = Some(strategy);
+    }
+
+    pub fn set_borrowed_types(&mut self, borrowed_types: BorrowedTypes) {
+        self.borrowed_types = Some(borrowed_types);
+    }
+
+    pub fn set_struct_name(&mut self, name: &'a str) {
+        self.struct_name = Some(name);
+    }
+
+    pub fn set_unique_columns(&mut self, cols: Vec<&'a str>) {
+        self.unique_columns = Some(cols);
+    }
+
+    pub fn set_indexed_columns(&mut self, cols: Vec<&'a str>) {
+        self.indexed_columns = Some(cols);
+    }
+
+    pub fn set_filter_struct(&mut self, bool: bool) {
+        self.filter_struct = Some(bool);
+    }
+
     /// Fills any `None` properties with values from another TableConfig
     pub fn apply_defaults(&self, other: &TableOptions<'a>) -> Self {
         Self {
@@ -160,12 +419,45 @@ impl<'a> TableOptions<'a> {
                 .autogenerated_columns
                 .clone()
                 .or_else(|| other.autogenerated_columns.clone()),
+            infer_autogenerated: self.infer_autogenerated.or(other.infer_autogenerated),
+            autogenerated_patterns: self
+                .autogenerated_patterns
+                .clone()
+                .or_else(|| other.autogenerated_patterns.clone()),
 
             use_serde: self.use_serde.or(other.use_serde),
             only_necessary_derives: self.only_necessary_derives.or(other.only_necessary_derives),
             read_only: self.read_only.or(other.read_only),
             impls: self.impls.or(other.impls),
             create_str_over_string: self.create_str_over_string.or(other.create_str_over_string),
+            exclude_columns: self
+                .exclude_columns
+                .clone()
+                .or_else(|| other.exclude_columns.clone()),
+            create_exclude: self
+                .create_exclude
+                .clone()
+                .or_else(|| other.create_exclude.clone()),
+            update_exclude: self
+                .update_exclude
+                .clone()
+                .or_else(|| other.update_exclude.clone()),
+            read_only_columns: self
+                .read_only_columns
+                .clone()
+                .or_else(|| other.read_only_columns.clone()),
+            update_strategy: self.update_strategy.or(other.update_strategy),
+            borrowed_types: self.borrowed_types.or(other.borrowed_types),
+            struct_name: self.struct_name.or(other.struct_name),
+            unique_columns: self
+                .unique_columns
+                .clone()
+                .or_else(|| other.unique_columns.clone()),
+            indexed_columns: self
+                .indexed_columns
+                .clone()
+                .or_else(|| other.indexed_columns.clone()),
+            filter_struct: self.filter_struct.or(other.filter_struct),
         }
     }
 }
@@ -173,6 +465,7 @@ impl<'a> TableOptions<'a> {
 #[derive(Debug, Clone)]
 pub struct GenerationConfig<'a> {
     /// Specific Table options for a given table
+    /// Key can also be a glob pattern (`*` and `?`) matching multiple tables, like `view_*`
     pub table_options: HashMap<&'a str, TableOptions<'a>>,
     /// Default table options, used when not in `table_options`
     pub default_table_options: TableOptions<'a>,
@@ -197,6 +490,39 @@ pub struct GenerationConfig<'a> {
     pub once_connection: bool,
     /// Lessen conflicts with diesel types
     pub lessen_conflicts: bool,
+    /// Variants for custom sql types (like postgres enums) to generate a rust enum for
+    /// Key is the type name in the database (like `mood`) or the rust name of the sql type (like `Mood`)
+    /// Value are all variants as named in the database
+    pub sql_type_variants: HashMap<&'a str, Vec<&'a str>>,
+    /// Rust types to use for sql types instead of the built-in mapping, like `Numeric` to `rust_decimal::Decimal`
+    /// Key is the diesel sql type name (case-insensitive), custom sql types can also be overridden
+    pub sql_type_overrides: HashMap<&'a str, &'a str>,
+    /// Rust types to use for specific columns, like `users.settings` to `crate::types::UserSettings`
+    /// Key is `table.column` (without the schema module), nullable columns still get wrapped in a [Option]
+    pub column_type_overrides: HashMap<&'a str, &'a str>,
+    /// Library to use for all date and time types
+    pub datetime_backend: DateTimeBackend,
+    /// Library to use for postgres `Inet` and `Cidr` types
+    pub ip_network_backend: IpNetworkBackend,
+    /// Database the schema is for, used to infer backend specific autogenerated columns like sqlite rowid aliases
+    /// A sqlite database input always marks its rowid aliases, regardless of this option
+    pub backend: Option<DatabaseBackend>,
+    /// Generate a `Range<T>` type into `common.rs` and use it for postgres range columns instead of a tuple of [std::ops::Bound]
+    pub range_type: bool,
+    /// Singularize table names for struct names, like `user_roles` to `UserRole`
+    pub singularize: bool,
+    /// Singulars for irregular plurals not handled by `singularize`, like `criteria` to `criterion`
+    /// Key is the plural as a whole table name or as the last word of a table name
+    pub singular_overrides: HashMap<&'a str, &'a str>,
+    /// Template for the name of the main struct, where `{name}` is replaced with the struct name of the table
+    /// Default `{name}`
+    pub read_struct_name: Option<String>,
+    /// Template for the name of the "Create*" struct, like `New{name}`
+    /// Default `Create{name}`
+    pub create_struct_name: Option<String>,
+    /// Template for the name of the "Update*" struct, like `{name}Changeset`
+    /// Default `Update{name}`
+    pub update_struct_name: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -207,16 +533,127 @@ pub enum FileMode {
     NewFile,
     /// Do nothing for the file
     None,
+    /// Do not change anything on disk, only report what [FileMode::Overwrite] would change
+    Check,
+}
+
+/// How the "Update*" struct sets columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpdateStrategy {
+    /// All fields are wrapped in a [Option], where `None` skips the column and `Some(None)` sets a nullable column to `NULL`
+    /// With serde, `null` and a missing field are distinguished with `serde_with::rust::double_option`
+    #[default]
+    Patch,
+    /// All fields have the type of the column and all columns are set, `None` sets a nullable column to `NULL`
+    Replace,
+}
+
+/// Borrowed types to use in "Create*" and "Update*" structs instead of `String`, `Vec<u8>` and `Vec<T>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorrowedTypes {
+    /// Use references, like `&'a str`, `&'a [u8]` and `&'a [T]`
+    /// With serde, arrays use `Cow<'a, [T]>` instead, because serde cannot deserialize into a `&'a [T]`
+    Ref,
+    /// Use [std::borrow::Cow], like `Cow<'a, str>`, `Cow<'a, [u8]>` and `Cow<'a, [T]>`
+    Cow,
+}
+
+impl BorrowedTypes {
+    /// Get the borrowed type for a owned rust type (without the top-level [Option]), if there is one
+    pub fn borrowed_type(&self, ty: &str, use_serde: bool) -> Option<String> {
+        let element = ty.strip_prefix("Vec<").and_then(|ty| ty.strip_suffix('>'));
+
+        let borrowed = match (self, ty, element) {
+            (BorrowedTypes::Ref, "String", _) => "&'a str".to_string(),
+            (BorrowedTypes::Ref, _, Some("u8")) => "&'a [u8]".to_string(),
+            (BorrowedTypes::Ref, _, Some(element)) if !use_serde => format!("&'a [{element}]"),
+            (BorrowedTypes::Cow, "String", _) => "std::borrow::Cow<'a, str>".to_string(),
+            (_, _, Some(element)) => format!("std::borrow::Cow<'a, [{element}]>"),
+            _ => return None,
+        };
+
+        Some(borrowed)
+    }
+}
+
+/// Library to use for date and time types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateTimeBackend {
+    /// Use the `chrono` crate, like `chrono::NaiveDateTime`
+    #[default]
+    Chrono,
+    /// Use the `time` crate, like `time::PrimitiveDateTime`
+    Time,
+}
+
+impl DateTimeBackend {
+    /// Get the diesel feature required for this backend
+    pub fn diesel_feature(&self) -> &'static str {
+        match self {
+            DateTimeBackend::Chrono => "chrono",
+            DateTimeBackend::Time => "time",
+        }
+    }
+}
+
+/// Database a schema is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// Schema is for postgres
+    Postgres,
+    /// Schema is for mysql
+    Mysql,
+    /// Schema is for sqlite, a single `Integer` primary key is a alias for the rowid
+    Sqlite,
+}
+
+/// Library to use for postgres network address types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IpNetworkBackend {
+    /// Use the `ipnetwork` crate (`ipnetwork::IpNetwork`), requires diesel feature "network-address"
+    #[default]
+    IpNetwork,
+    /// Use the `ipnet` crate (`ipnet::IpNet`), requires diesel feature "ipnet-address"
+    IpNet,
+}
+
+impl IpNetworkBackend {
+    /// Get the rust type used for `Inet` and `Cidr`
+    pub fn rust_type(&self) -> &'static str {
+        match self {
+            IpNetworkBackend::IpNetwork => "ipnetwork::IpNetwork",
+            IpNetworkBackend::IpNet => "ipnet::IpNet",
+        }
+    }
+}
+
+impl FileMode {
+    /// Get whether the mode does not change anything on disk, not even "mod.rs" files
+    fn is_dry_run(&self) -> bool {
+        matches!(self, FileMode::Check)
+    }
 }
 
 impl GenerationConfig<'_> {
-    pub fn table(&self, name: &str) -> TableOptions<'_> {
-        let res = self
-            .table_options
-            .get(name)
-            .unwrap_or(&self.default_table_options);
+    /// Get the struct name for a table, before the struct name templates are applied
+    /// Uses the table option `struct_name` if set, otherwise the (singularized) table name in PascalCase
+    pub fn struct_name(&self, table_name: &str) -> String {
+        if let Some(name) = self.table(table_name).get_struct_name() {
+            return name.to_string();
+        }
 
-        let mut res = res.apply_defaults(&self.default_table_options);
+        if self.singularize {
+            naming::singularize(table_name, &self.singular_overrides).to_pascal_case()
+        } else {
+            table_name.to_pascal_case()
+        }
+    }
+
+    pub fn table(&self, name: &str) -> TableOptions<'_> {
+        let mut res = match self.table_specific(name) {
+            Some(res) => res.apply_defaults(&self.default_table_options),
+            None => self.default_table_options.clone(),
+        };
 
         if let Some(ref read_only_prefix) = self.read_only_prefix {
             if read_only_prefix.iter().any(|v| name.starts_with(v)) {
@@ -226,6 +663,80 @@ impl GenerationConfig<'_> {
 
         res
     }
+
+    /// Get the rust type override for a sql type, matched case-insensitive
+    pub(crate) fn sql_type_override(&self, sql_type: &str) -> Option<&str> {
+        self.sql_type_overrides
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(sql_type))
+            .map(|(_, ty)| *ty)
+    }
+
+    /// Get the rust type override for a specific column
+    pub(crate) fn column_type_override(&self, table: &str, column: &str) -> Option<&str> {
+        self.column_type_overrides
+            .iter()
+            .find(|(key, _)| key.split_once('.') == Some((table, column)))
+            .map(|(_, ty)| *ty)
+    }
+
+    /// Get the options specific to a table, without applying the default table options
+    /// A exact name match is used over glob patterns, if multiple patterns match, the longer (more specific) pattern takes precedence
+    pub(crate) fn table_specific(&self, name: &str) -> Option<TableOptions<'_>> {
+        if let Some(res) = self.table_options.get(name) {
+            return Some(res.clone());
+        }
+
+        let mut matches = self
+            .table_options
+            .iter()
+            .filter(|(pattern, _)| glob_match(pattern, name))
+            .collect::<Vec<_>>();
+
+        matches.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+        matches
+            .into_iter()
+            .map(|(_, options)| options)
+            .fold(None, |res, options| match res {
+                Some(res) => Some(res.apply_defaults(options)),
+                None => Some(options.clone()),
+            })
+    }
+}
+
+/// Match `name` against a glob `pattern`, where `*` matches any amount of characters and `?` matches exactly one
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    let (mut p, mut n) = (0, 0);
+    // position of the last "*" in the pattern and the position in the name it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // let the last "*" match one more character
+                Some((star_p, star_n)) => {
+                    backtrack = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
 }
 
 /// Generate a model for a given schema
@@ -233,7 +744,7 @@ impl GenerationConfig<'_> {
 pub fn generate_code(
     diesel_schema_file_contents: String,
     config: &GenerationConfig,
-) -> Result<Vec<ParsedTableMacro>> {
+) -> Result<ParsedSchema> {
     parser::parse_and_generate_code(diesel_schema_file_contents, config)
 }
 
@@ -250,6 +761,8 @@ pub enum FileChangesStatus {
     NewFile(PathBuf),
     /// Status to mark file contents to be modified
     Modified,
+    /// Status to mark a file to be created
+    Created,
     /// Status if the file has been deleted
     Deleted,
     /// Status if the file should be deleted, but is not because of some ignore rule (like [FileMode::None])
@@ -266,6 +779,7 @@ impl Display for FileChangesStatus {
                 FileChangesStatus::UnchangedIgnored => "Unchanged(Ignored)",
                 FileChangesStatus::Overwritten => "Overwritten",
                 FileChangesStatus::Modified => "Modified",
+                FileChangesStatus::Created => "Created",
                 FileChangesStatus::Deleted => "Deleted",
                 FileChangesStatus::DeletedIgnored => "Deleted(Ignored)",
                 FileChangesStatus::NewFile(_) => "NewFile",
@@ -280,6 +794,8 @@ pub struct FileChanges {
     pub file: PathBuf,
     /// Status of the file
     pub status: FileChangesStatus,
+    /// Contents before and after, only set if the contents changed (or would change)
+    pub diff: Option<FileDiff>,
 }
 
 impl FileChanges {
@@ -287,6 +803,15 @@ impl FileChanges {
         Self {
             file: path.as_ref().to_owned(),
             status,
+            diff: None,
+        }
+    }
+
+    /// Create a new instance for a file that is (or should be) deleted
+    pub fn from_deleted_markedfile(marked_file: &MarkedFile, status: FileChangesStatus) -> Self {
+        Self {
+            diff: Some(marked_file.deleted_diff()),
+            ..Self::new(marked_file, status)
         }
     }
 
@@ -298,7 +823,17 @@ impl FileChanges {
         status_unmodified: FileChangesStatus,
     ) -> Self {
         if marked_file.is_modified() {
-            Self::new(marked_file, status_modified)
+            // a modified file which did not exist before is created instead
+            let status = if marked_file.is_new() && status_modified == FileChangesStatus::Modified {
+                FileChangesStatus::Created
+            } else {
+                status_modified
+            };
+
+            Self {
+                diff: Some(marked_file.diff()),
+                ..Self::new(marked_file, status)
+            }
         } else {
             Self::new(marked_file, status_unmodified)
         }
@@ -341,6 +876,7 @@ fn write_file(
             (true, FileChangesStatus::NewFile(old_path))
         }
         FileMode::None => (false, FileChangesStatus::UnchangedIgnored),
+        FileMode::Check => (false, FileChangesStatus::Modified),
     };
 
     // additional "is_modified" check, because "newfile" changed the path and write would generate a file even if unchanged
@@ -358,23 +894,84 @@ fn write_file(
     Ok(())
 }
 
-/// Generate all models for a given diesel schema input file
+/// Result of [generate_files]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationResult {
+    /// Status of all files that were touched
+    pub file_changes: Vec<FileChanges>,
+    /// All non-fatal warnings found during generation
+    pub warnings: Vec<Warning>,
+    /// All columns treated as autogenerated because of the input or inference, not from `autogenerated_columns`
+    pub autogenerated_columns: Vec<AutogeneratedColumn>,
+}
+
+/// A column which is treated as autogenerated without being listed in the autogenerated columns option
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutogeneratedColumn {
+    /// Name of the table
+    pub table: String,
+    /// Rust name of the column
+    pub column: String,
+    /// Why the column is autogenerated
+    pub reason: AutogeneratedReason,
+}
+
+impl GenerationResult {
+    /// Get all files that were (or with [FileMode::Check] would be) changed
+    pub fn changed_files(&self) -> impl Iterator<Item = &FileChanges> {
+        self.file_changes.iter().filter(|change| {
+            matches!(
+                change.status,
+                FileChangesStatus::Modified
+                    | FileChangesStatus::Created
+                    | FileChangesStatus::Deleted
+                    | FileChangesStatus::Overwritten
+                    | FileChangesStatus::NewFile(_)
+            )
+        })
+    }
+}
+
+/// Generate all models for a given diesel schema input file, sql migrations or a sqlite database with feature "sqlite"
 /// Models are saved to disk
 pub fn generate_files(
     input_diesel_schema_file: PathBuf,
     output_models_dir: PathBuf,
     config: GenerationConfig,
-) -> Result<Vec<FileChanges>> {
+) -> Result<GenerationResult> {
     let input = input_diesel_schema_file;
     let output_dir = output_models_dir;
 
-    let generated = generate_code(
-        std::fs::read_to_string(&input).attach_path_err(&input)?,
-        &config,
-    )?;
+    for (option, template) in [
+        ("read_struct_name", &config.read_struct_name),
+        ("create_struct_name", &config.create_struct_name),
+        ("update_struct_name", &config.update_struct_name),
+    ] {
+        if let Some(template) = template {
+            if !template.contains("{name}") {
+                return Err(Error::other(format!(
+                    "Option \"{option}\" needs to contain \"{{name}}\", got \"{template}\""
+                )));
+            }
+        }
+    }
+
+    let generated = if migrations::is_migrations_input(&input) {
+        migrations::parse_and_generate_code(&input, &config)?
+    } else if sqlite::is_sqlite_database(&input) {
+        sqlite::parse_and_generate_code(&input, &config)?
+    } else {
+        generate_code(
+            std::fs::read_to_string(&input).attach_path_err(&input)?,
+            &config,
+        )
+        .map_err(|err| err.with_path(&input))?
+    };
 
     if !output_dir.exists() {
-        std::fs::create_dir(&output_dir).attach_path_err(&output_dir)?;
+        if !config.file_mode.is_dry_run() {
+            std::fs::create_dir(&output_dir).attach_path_err(&output_dir)?;
+        }
     } else if !output_dir.is_dir() {
         return Err(Error::not_a_directory(
             "Expected output argument to be a directory or non-existent.",
@@ -387,65 +984,241 @@ pub fn generate_files(
     // check that the mod.rs file exists
     let mut mod_rs = MarkedFile::new(output_dir.join("mod.rs"))?;
 
-    let mut common_file = MarkedFile::new(output_dir.join("common.rs"))?;
+    let mut common_parts = Vec::new();
 
-    // dont check file signature if a ".dsyncnew" file will be generated
-    if config.file_mode != FileMode::NewFile {
-        common_file.ensure_file_signature()?;
+    if config.once_common_structs {
+        common_parts.push(code::generate_common_structs(&config.default_table_options));
     }
 
-    common_file.change_file_contents_no_modify(format!("{}\n", FILE_SIGNATURE));
+    if config.once_connection {
+        common_parts.push(format!(
+            "/// Connection Type as set in dsync\npub type {} = {};\n",
+            get_connection_type_name(&config),
+            config.connection_type
+        ));
+    }
 
-    if config.once_common_structs {
-        common_file.change_file_contents({
-            let mut tmp = String::from(common_file.get_file_contents());
-            tmp.push_str(&code::generate_common_structs(
-                &config.default_table_options,
-            ));
-            tmp
-        });
+    if config.range_type {
+        common_parts.push(code::generate_range_type(
+            config.default_table_options.get_serde(),
+        ));
     }
 
-    if config.once_connection {
-        common_file.change_file_contents({
-            let mut tmp = String::from(common_file.get_file_contents());
-            if !common_file.is_empty() {
-                tmp.push('\n');
-            }
-            tmp.push_str(&format!(
-                "/// Connection Type as set in dsync\npub type {} = {};\n",
-                get_connection_type_name(&config),
-                config.connection_type
+    // only create "common.rs" if there is something to put into it
+    if !common_parts.is_empty() {
+        let mut common_file = MarkedFile::new(output_dir.join("common.rs"))?;
+
+        // dont check file signature if a ".dsyncnew" file will be generated
+        if config.file_mode != FileMode::NewFile {
+            common_file.ensure_file_signature()?;
+        }
+
+        common_file.change_file_contents(format!(
+            "{}\n{}",
+            FILE_SIGNATURE,
+            common_parts.join("\n")
+        ));
+
+        // always write the "mod" statement, even if "write_file" is not writing
+        mod_rs.ensure_mod_stmt("common");
+
+        write_file(&config, common_file, &mut file_status)?;
+    }
+
+    // collect all schema modules, "None" being the top-level
+    let mut schemas: Vec<Option<&Ident>> = vec![None];
+    for schema in generated
+        .tables
+        .iter()
+        .map(|table| table.schema.as_ref())
+        .chain(
+            generated
+                .sql_types_code
+                .iter()
+                .map(|(schema, _)| schema.as_ref()),
+        )
+    {
+        if !schemas.contains(&schema) {
+            schemas.push(schema);
+        }
+    }
+
+    for schema in schemas {
+        let Some(schema) = schema else {
+            generate_files_for_dir(
+                &config,
+                &generated,
+                None,
+                &output_dir,
+                &mut mod_rs,
+                &mut file_status,
+            )?;
+
+            continue;
+        };
+
+        let schema_name = schema.to_string();
+
+        if config.once_common_structs && schema_name == "common" {
+            return Err(Error::other("Cannot have a schema named \"common\" while having option \"once_common_structs\" enabled"));
+        }
+
+        if config.range_type && schema_name == "common" {
+            return Err(Error::other(
+                "Cannot have a schema named \"common\" while having option \"range_type\" enabled",
             ));
-            tmp
+        }
+
+        let schema_dir = output_dir.join(&schema_name);
+
+        if !schema_dir.exists() {
+            if !config.file_mode.is_dry_run() {
+                std::fs::create_dir(&schema_dir).attach_path_err(&schema_dir)?;
+            }
+        } else if !schema_dir.is_dir() {
+            return Err(Error::not_a_directory("Expected a directory", schema_dir));
+        }
+
+        let mut schema_mod_rs = MarkedFile::new(schema_dir.join("mod.rs"))?;
+
+        generate_files_for_dir(
+            &config,
+            &generated,
+            Some(schema),
+            &schema_dir,
+            &mut schema_mod_rs,
+            &mut file_status,
+        )?;
+
+        // always write the "mod" statement, even if "write_file" is not writing
+        if !config.file_mode.is_dry_run() {
+            schema_mod_rs.write()?;
+        }
+
+        file_status.push(FileChanges::from(&schema_mod_rs));
+
+        mod_rs.ensure_mod_stmt(&schema_name);
+    }
+
+    // always write the "mod" statement, even if "write_file" is not writing
+    if !config.file_mode.is_dry_run() {
+        mod_rs.write()?;
+    }
+
+    file_status.push(FileChanges::from(&mod_rs));
+
+    let autogenerated_columns = generated
+        .tables
+        .iter()
+        .flat_map(|table| {
+            table.columns.iter().filter_map(|column| {
+                Some(AutogeneratedColumn {
+                    table: table.name.to_string(),
+                    column: column.name.to_string(),
+                    reason: column.autogenerated.clone()?,
+                })
+            })
         })
+        .collect();
+
+    Ok(GenerationResult {
+        file_changes: file_status,
+        warnings: generated.warnings,
+        autogenerated_columns,
+    })
+}
+
+/// Generate and cleanup all files for a given schema module (`None` being the top-level) in the given directory
+/// `mod_rs` is the "mod.rs" of the given directory
+fn generate_files_for_dir(
+    config: &GenerationConfig,
+    generated: &ParsedSchema,
+    schema: Option<&Ident>,
+    dir: &Path,
+    mod_rs: &mut MarkedFile,
+    file_status: &mut Vec<FileChanges>,
+) -> Result<()> {
+    let mut types_file = MarkedFile::new(dir.join("types.rs"))?;
+
+    // dont check file signature if a ".dsyncnew" file will be generated
+    if config.file_mode != FileMode::NewFile {
+        types_file.ensure_file_signature()?;
     }
 
-    if !common_file.is_empty() {
+    let sql_types_code = generated
+        .sql_types_code
+        .iter()
+        .find(|(sql_types_schema, _)| sql_types_schema.as_ref() == schema)
+        .map(|(_, code)| code);
+
+    if let Some(sql_types_code) = sql_types_code {
+        types_file.change_file_contents(sql_types_code.clone());
+
         // always write the "mod" statement, even if "write_file" is not writing
-        mod_rs.ensure_mod_stmt("common");
-    }
+        mod_rs.ensure_mod_stmt("types");
 
-    write_file(&config, common_file, &mut file_status)?;
+        write_file(config, types_file, file_status)?;
+    } else if types_file.path.exists() && !types_file.get_file_contents().is_empty() {
+        // there are no custom sql types anymore, so remove the previously generated file
+        match config.file_mode {
+            FileMode::Overwrite => {
+                file_status.push(FileChanges::from_deleted_markedfile(
+                    &types_file,
+                    FileChangesStatus::Deleted,
+                ));
+                types_file.delete()?;
+                mod_rs.remove_mod_stmt("types");
+            }
+            FileMode::Check => {
+                file_status.push(FileChanges::from_deleted_markedfile(
+                    &types_file,
+                    FileChangesStatus::Deleted,
+                ));
+                mod_rs.remove_mod_stmt("types");
+            }
+            FileMode::NewFile | FileMode::None => {
+                file_status.push(FileChanges::from_deleted_markedfile(
+                    &types_file,
+                    FileChangesStatus::DeletedIgnored,
+                ));
+            }
+        }
+    }
 
     // pass 1: add code for new tables
-    for table in generated.iter() {
-        if config.once_common_structs && table.name == "common" {
+    for table in generated
+        .tables
+        .iter()
+        .filter(|table| table.schema.as_ref() == schema)
+    {
+        if config.once_common_structs && schema.is_none() && table.name == "common" {
             return Err(Error::other("Cannot have a table named \"common\" while having option \"once_common_structs\" enabled"));
         }
 
+        if config.range_type && schema.is_none() && table.name == "common" {
+            return Err(Error::other(
+                "Cannot have a table named \"common\" while having option \"range_type\" enabled",
+            ));
+        }
+
+        if sql_types_code.is_some() && table.name == "types" {
+            return Err(Error::other(
+                "Cannot have a table named \"types\" while having custom sql types",
+            ));
+        }
+
         let table_name = table.name.to_string();
         let table_dir = if config.single_model_file {
-            output_dir.clone()
+            dir.to_path_buf()
         } else {
-            output_dir.join(&table_name)
+            dir.join(&table_name)
         };
 
         if !table_dir.exists() {
-            std::fs::create_dir(&table_dir).attach_path_err(&table_dir)?;
-        }
-
-        if !table_dir.is_dir() {
+            if !config.file_mode.is_dry_run() {
+                std::fs::create_dir(&table_dir).attach_path_err(&table_dir)?;
+            }
+        } else if !table_dir.is_dir() {
             return Err(Error::not_a_directory("Expected a directory", table_dir));
         }
 
@@ -475,7 +1248,7 @@ pub fn generate_files(
                 .clone(),
         );
 
-        write_file(&config, table_generated_rs, &mut file_status)?;
+        write_file(config, table_generated_rs, file_status)?;
 
         if !config.single_model_file {
             let mut table_mod_rs = MarkedFile::new(table_dir.join("mod.rs"))?;
@@ -483,7 +1256,9 @@ pub fn generate_files(
             table_mod_rs.ensure_mod_stmt("generated");
             table_mod_rs.ensure_use_stmt("generated::*");
             // always write the "mod" statement, even if "write_file" is not writing
-            table_mod_rs.write()?;
+            if !config.file_mode.is_dry_run() {
+                table_mod_rs.write()?;
+            }
 
             file_status.push(FileChanges::from(&table_mod_rs));
         }
@@ -491,9 +1266,14 @@ pub fn generate_files(
         mod_rs.ensure_mod_stmt(table.name.to_string().as_str());
     }
 
+    // the directory may not exist yet when not writing to disk
+    if !dir.exists() {
+        return Ok(());
+    }
+
     // pass 2: delete code for removed tables
-    for item in std::fs::read_dir(&output_dir).attach_path_err(&output_dir)? {
-        let item = item.attach_path_err(&output_dir)?;
+    for item in std::fs::read_dir(dir).attach_path_err(dir)? {
+        let item = item.attach_path_err(dir)?;
 
         // check if item is a directory
         let file_type = item
@@ -505,10 +1285,12 @@ pub fn generate_files(
 
         // check if it's a generated file
         let generated_rs_path = item.path().join("generated.rs");
-        if !generated_rs_path.exists()
-            || !generated_rs_path.is_file()
-            || !MarkedFile::new(generated_rs_path.clone())?.has_file_signature()
-        {
+        if !generated_rs_path.exists() || !generated_rs_path.is_file() {
+            continue;
+        }
+
+        let generated_rs = MarkedFile::new(generated_rs_path)?;
+        if !generated_rs.has_file_signature() {
             continue;
         }
 
@@ -518,10 +1300,11 @@ pub fn generate_files(
             "Could not determine name of file '{:#?}'",
             item.path()
         )))?;
-        let found = generated.iter().find(|g| {
-            g.name
-                .to_string()
-                .eq_ignore_ascii_case(associated_table_name)
+        let found = generated.tables.iter().find(|g| {
+            g.schema.as_ref() == schema
+                && g.name
+                    .to_string()
+                    .eq_ignore_ascii_case(associated_table_name)
         });
         if found.is_some() {
             continue;
@@ -530,15 +1313,21 @@ pub fn generate_files(
         match config.file_mode {
             FileMode::Overwrite => {
                 // this table was deleted, let's delete the generated code
-                std::fs::remove_file(&generated_rs_path).attach_path_err(&generated_rs_path)?;
-                file_status.push(FileChanges::new(
-                    &generated_rs_path,
+                file_status.push(FileChanges::from_deleted_markedfile(
+                    &generated_rs,
+                    FileChangesStatus::Deleted,
+                ));
+                generated_rs.delete()?;
+            }
+            FileMode::Check => {
+                file_status.push(FileChanges::from_deleted_markedfile(
+                    &generated_rs,
                     FileChangesStatus::Deleted,
                 ));
             }
             FileMode::NewFile | FileMode::None => {
-                file_status.push(FileChanges::new(
-                    &generated_rs_path,
+                file_status.push(FileChanges::from_deleted_markedfile(
+                    &generated_rs,
                     FileChangesStatus::DeletedIgnored,
                 ));
             }
@@ -554,10 +1343,18 @@ pub fn generate_files(
 
             if table_mod_rs.get_file_contents().trim().is_empty() {
                 if config.file_mode == FileMode::Overwrite {
-                    let table_mod_rs = table_mod_rs.delete()?;
-                    file_status.push(FileChanges::new(&table_mod_rs, FileChangesStatus::Deleted));
+                    file_status.push(FileChanges::from_deleted_markedfile(
+                        &table_mod_rs,
+                        FileChangesStatus::Deleted,
+                    ));
+                    table_mod_rs.delete()?;
+                } else if config.file_mode == FileMode::Check {
+                    file_status.push(FileChanges::from_deleted_markedfile(
+                        &table_mod_rs,
+                        FileChangesStatus::Deleted,
+                    ));
                 } else {
-                    file_status.push(FileChanges::new(
+                    file_status.push(FileChanges::from_deleted_markedfile(
                         &table_mod_rs,
                         FileChangesStatus::DeletedIgnored,
                     ));
@@ -566,6 +1363,7 @@ pub fn generate_files(
                 // not using "write_file" because of custom "NewFile" handling
                 let (write, file_change_status) = match config.file_mode {
                     FileMode::Overwrite => (true, FileChangesStatus::Modified),
+                    FileMode::Check => (false, FileChangesStatus::Modified),
                     FileMode::NewFile | FileMode::None => {
                         (false, FileChangesStatus::UnchangedIgnored)
                     }
@@ -591,21 +1389,16 @@ pub fn generate_files(
             .attach_path_err(item.path())?
             .next()
             .is_none();
-        if is_empty {
+        if is_empty && !config.file_mode.is_dry_run() {
             std::fs::remove_dir(item.path()).attach_path_err(item.path())?;
         }
 
-        // dont remove "mod" statement on delete for anything other than ::Overwrite
-        if config.file_mode == FileMode::Overwrite {
+        // dont remove "mod" statement on delete for anything other than ::Overwrite (or ::Check, which reports like it)
+        if matches!(config.file_mode, FileMode::Overwrite | FileMode::Check) {
             // remove the module from the main mod_rs file
             mod_rs.remove_mod_stmt(associated_table_name);
         }
     }
 
-    // always write the "mod" statement, even if "write_file" is not writing
-    mod_rs.write()?;
-
-    file_status.push(FileChanges::from(&mod_rs));
-
-    Ok(file_status)
+    Ok(())
 }
@@ -0,0 +1,619 @@
+use std::path::{Path, PathBuf};
+
+use sqlparser::ast::{
+    AlterColumnOperation, AlterTableOperation, ArrayElemTypeDef, ColumnDef, ColumnOption,
+    CommentObject, DataType, Expr, ObjectName, ObjectType, RenameTableNameKind, Statement,
+    TableConstraint,
+};
+use syn::Ident;
+
+use crate::error::IOErrorToError;
+use crate::parser::{self, ParsedColumnMacro, ParsedColumnType, ParsedJoinMacro, ParsedSchema};
+use crate::{Error, GenerationConfig, Result};
+
+/// A table as built up by all migrations until now
+#[derive(Debug, Clone)]
+struct MigrationTable {
+    /// Schema the table was created in, if the name was qualified with one
+    schema: Option<String>,
+    /// Name of the table in the database
+    name: String,
+    columns: Vec<MigrationColumn>,
+    /// Names of the primary key columns
+    primary_keys: Vec<String>,
+    /// Name of the primary key constraint, if named
+    primary_key_constraint: Option<String>,
+    foreign_keys: Vec<MigrationForeignKey>,
+}
+
+/// A column as built up by all migrations until now
+#[derive(Debug, Clone)]
+struct MigrationColumn {
+    /// Name of the column in the database
+    name: String,
+    /// Type of the column without the nullability
+    sql_type: ParsedColumnType,
+    not_null: bool,
+    /// Whether the column has a "DEFAULT", is "GENERATED" or otherwise set by the database
+    is_autogenerated: bool,
+    comment: Option<String>,
+}
+
+/// A single column foreign key, as supported by "joinable!"
+#[derive(Debug, Clone)]
+struct MigrationForeignKey {
+    /// Name of the constraint, if named
+    constraint: Option<String>,
+    column: String,
+    foreign_table: String,
+}
+
+/// Get whether `path` should be read as sql migrations, which is the case for directories and ".sql" files
+pub fn is_migrations_input(path: &Path) -> bool {
+    path.is_dir() || path.extension().is_some_and(|ext| ext == "sql")
+}
+
+/// Read all tables from sql migrations instead of a diesel schema file
+/// `path` can either be a diesel "migrations" directory, where all "up.sql" are applied in order, or a single ".sql" file
+pub fn parse_and_generate_code(path: &Path, config: &GenerationConfig) -> Result<ParsedSchema> {
+    let files = if path.is_dir() {
+        migration_files(path)?
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut tables: Vec<MigrationTable> = vec![];
+
+    for file in files {
+        let contents = std::fs::read_to_string(&file).attach_path_err(&file)?;
+        let statements =
+            sqlparser::parser::Parser::parse_sql(&sqlparser::dialect::GenericDialect {}, &contents)
+                .map_err(|err| {
+                    Error::unsupported_schema_format(format!(
+                        "Could not parse migration \"{}\": {err}",
+                        file.display()
+                    ))
+                })?;
+
+        for statement in statements {
+            apply_statement(&mut tables, statement)?;
+        }
+    }
+
+    let mut parsed_tables = vec![];
+    let mut joins = vec![];
+    let mut warnings = vec![];
+
+    for table in tables {
+        let table_ident = parser::to_rust_ident(&table.name);
+
+        if config.table(&table_ident.to_string()).get_ignore() {
+            continue;
+        }
+
+        let mut columns = vec![];
+
+        for column in table.columns {
+            let is_primary_key = table.primary_keys.contains(&column.name);
+
+            // primary keys are always "NOT NULL"
+            let sql_type = if !column.not_null && !is_primary_key {
+                ParsedColumnType::Nullable(Box::new(column.sql_type))
+            } else {
+                column.sql_type
+            };
+
+            let mut parsed_column = ParsedColumnMacro::new(
+                Some(&table_ident),
+                parser::to_rust_ident(&column.name),
+                column.name,
+                sql_type,
+                config,
+                &[],
+                None,
+            )?;
+            parsed_column.autogenerated = column
+                .is_autogenerated
+                .then_some(parser::AutogeneratedReason::DatabaseDefault);
+            parsed_column.comment = column.comment;
+
+            columns.push(parsed_column);
+        }
+
+        let mut primary_keys: Vec<Ident> = table
+            .primary_keys
+            .iter()
+            .map(|name| parser::to_rust_ident(name))
+            .collect();
+
+        // same default as the diesel "table!" macro
+        if primary_keys.is_empty() {
+            primary_keys.push(Ident::new("id", proc_macro2::Span::call_site()));
+        }
+
+        for foreign_key in table.foreign_keys {
+            let foreign_table = parser::to_rust_ident(&foreign_key.foreign_table);
+
+            // "joinable!" (and so "belongs_to") only supports one foreign key per table pair
+            if joins.iter().any(|(_, join): &(_, ParsedJoinMacro)| {
+                join.table1 == table_ident && join.table2 == foreign_table
+            }) {
+                continue;
+            }
+
+            joins.push((
+                None,
+                ParsedJoinMacro {
+                    table1: table_ident.clone(),
+                    table2: foreign_table,
+                    table1_columns: parser::to_rust_ident(&foreign_key.column).to_string(),
+                },
+            ));
+        }
+
+        parsed_tables.push(parser::finish_table(
+            table_ident,
+            columns,
+            primary_keys,
+            config,
+            &[],
+            None,
+            &mut warnings,
+        ));
+    }
+
+    parser::generate_schema(parsed_tables, vec![], joins, warnings, config)
+}
+
+/// Get all "up.sql" files of a diesel migrations directory, in the order they are applied
+fn migration_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .attach_path_err(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join("up.sql"))
+        .filter(|path| path.is_file())
+        .collect();
+
+    // migrations are applied in order of the directory names
+    files.sort();
+
+    if files.is_empty() {
+        return Err(Error::unsupported_schema_format(format!(
+            "Expected migrations directory \"{}\" to contain migrations with a \"up.sql\"",
+            dir.display()
+        )));
+    }
+
+    Ok(files)
+}
+
+/// Apply a single sql statement to the tables, statements which do not change tables are ignored
+///
+/// Tables are only identified by their name, so tables with the same name in different schemas are a error
+fn apply_statement(tables: &mut Vec<MigrationTable>, statement: Statement) -> Result<()> {
+    match statement {
+        Statement::CreateTable(create) => {
+            let schema = object_schema(&create.name);
+            let name = object_name(&create.name);
+
+            if let Some(existing) = tables.iter().find(|table| table.name == name) {
+                if let (Some(existing_schema), Some(schema)) = (&existing.schema, &schema) {
+                    if existing_schema != schema {
+                        return Err(Error::unsupported_schema_format(format!(
+                            "Tables with the same name in different schemas are not supported, found \"{existing_schema}.{name}\" and \"{schema}.{name}\""
+                        )));
+                    }
+                }
+
+                // "IF NOT EXISTS" does nothing and otherwise the migration would have failed
+                return Ok(());
+            }
+
+            let mut table = MigrationTable {
+                schema,
+                name,
+                columns: vec![],
+                primary_keys: vec![],
+                primary_key_constraint: None,
+                foreign_keys: vec![],
+            };
+
+            for column in create.columns {
+                add_column(&mut table, column);
+            }
+
+            for constraint in create.constraints {
+                add_constraint(&mut table, constraint);
+            }
+
+            tables.push(table);
+        }
+        Statement::AlterTable(alter) => {
+            let name = object_name(&alter.name);
+
+            for operation in alter.operations {
+                alter_table(tables, &name, operation);
+            }
+        }
+        Statement::Drop {
+            object_type: ObjectType::Table,
+            names,
+            ..
+        } => {
+            for name in names.iter().map(object_name) {
+                tables.retain(|table| table.name != name);
+
+                // foreign keys to the table cannot exist anymore
+                for table in tables.iter_mut() {
+                    table
+                        .foreign_keys
+                        .retain(|foreign_key| foreign_key.foreign_table != name);
+                }
+            }
+        }
+        Statement::Comment {
+            object_type: CommentObject::Column,
+            object_name,
+            comment,
+            ..
+        } => {
+            let parts = object_name_parts(&object_name);
+
+            // "table.column", optionally with the schema in front
+            if let [.., table_name, column_name] = parts.as_slice() {
+                if let Some(column) =
+                    find_table(tables, table_name).and_then(|table| find_column(table, column_name))
+                {
+                    column.comment = comment;
+                }
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Apply a single "ALTER TABLE" operation to the table `name`
+fn alter_table(tables: &mut [MigrationTable], name: &str, operation: AlterTableOperation) {
+    // renaming the table also needs access to all other tables
+    if let AlterTableOperation::RenameTable { table_name } = &operation {
+        let new_name = match table_name {
+            RenameTableNameKind::As(new_name) | RenameTableNameKind::To(new_name) => {
+                object_name(new_name)
+            }
+        };
+
+        for table in tables.iter_mut() {
+            if table.name == name {
+                table.name = new_name.clone();
+            }
+
+            for foreign_key in table.foreign_keys.iter_mut() {
+                if foreign_key.foreign_table == name {
+                    foreign_key.foreign_table = new_name.clone();
+                }
+            }
+        }
+
+        return;
+    }
+
+    let Some(table) = find_table(tables, name) else {
+        return;
+    };
+
+    match operation {
+        AlterTableOperation::AddColumn { column_def, .. } => add_column(table, column_def),
+        AlterTableOperation::DropColumn { column_names, .. } => {
+            for column_name in column_names.iter().map(ident_name) {
+                table.columns.retain(|column| column.name != column_name);
+                table.primary_keys.retain(|column| column != &column_name);
+                table
+                    .foreign_keys
+                    .retain(|foreign_key| foreign_key.column != column_name);
+            }
+        }
+        AlterTableOperation::RenameColumn {
+            old_column_name,
+            new_column_name,
+        } => rename_column(
+            table,
+            &ident_name(&old_column_name),
+            ident_name(&new_column_name),
+        ),
+        AlterTableOperation::ChangeColumn {
+            old_name,
+            new_name,
+            data_type,
+            options,
+            ..
+        } => {
+            let old_name = ident_name(&old_name);
+            let new_name = ident_name(&new_name);
+
+            rename_column(table, &old_name, new_name.clone());
+            set_column_definition(table, &new_name, &data_type, unnamed(options));
+        }
+        AlterTableOperation::ModifyColumn {
+            col_name,
+            data_type,
+            options,
+            ..
+        } => set_column_definition(table, &ident_name(&col_name), &data_type, unnamed(options)),
+        AlterTableOperation::AlterColumn { column_name, op } => {
+            let Some(column) = find_column(table, &ident_name(&column_name)) else {
+                return;
+            };
+
+            match op {
+                AlterColumnOperation::SetNotNull => column.not_null = true,
+                AlterColumnOperation::DropNotNull => column.not_null = false,
+                AlterColumnOperation::SetDefault { .. }
+                | AlterColumnOperation::AddGenerated { .. } => column.is_autogenerated = true,
+                AlterColumnOperation::DropDefault => column.is_autogenerated = false,
+                AlterColumnOperation::SetDataType { data_type, .. } => {
+                    column.sql_type = data_type_to_column_type(&data_type).0;
+                }
+            }
+        }
+        AlterTableOperation::AddConstraint { constraint, .. } => add_constraint(table, constraint),
+        AlterTableOperation::DropPrimaryKey { .. } => {
+            table.primary_keys.clear();
+            table.primary_key_constraint = None;
+        }
+        AlterTableOperation::DropConstraint { name, .. }
+        | AlterTableOperation::DropForeignKey { name, .. } => {
+            let name = ident_name(&name);
+
+            if table.primary_key_constraint.as_ref() == Some(&name) {
+                table.primary_keys.clear();
+                table.primary_key_constraint = None;
+            }
+
+            table
+                .foreign_keys
+                .retain(|foreign_key| foreign_key.constraint.as_ref() != Some(&name));
+        }
+        _ => (),
+    }
+}
+
+/// Add a column definition to `table`, including the primary key and foreign key column options
+fn add_column(table: &mut MigrationTable, column_def: ColumnDef) {
+    let name = ident_name(&column_def.name);
+
+    table.columns.push(MigrationColumn {
+        name: name.clone(),
+        sql_type: ParsedColumnType::Base(Ident::new("Text", proc_macro2::Span::call_site())),
+        not_null: false,
+        is_autogenerated: false,
+        comment: None,
+    });
+
+    set_column_definition(
+        table,
+        &name,
+        &column_def.data_type,
+        column_def
+            .options
+            .into_iter()
+            .map(|option| (option.name.as_ref().map(ident_name), option.option))
+            .collect(),
+    );
+}
+
+/// Column options from "ALTER TABLE" cannot have a constraint name
+fn unnamed(options: Vec<ColumnOption>) -> Vec<(Option<String>, ColumnOption)> {
+    options.into_iter().map(|option| (None, option)).collect()
+}
+
+/// Set the type and options (with the constraint name, if any) of the existing column `name`, like for a new column
+fn set_column_definition(
+    table: &mut MigrationTable,
+    name: &str,
+    data_type: &DataType,
+    options: Vec<(Option<String>, ColumnOption)>,
+) {
+    let (sql_type, is_serial) = data_type_to_column_type(data_type);
+    // "SERIAL" types are implicitly "NOT NULL"
+    let mut not_null = is_serial;
+    let mut is_autogenerated = is_serial;
+    let mut comment = None;
+
+    for (constraint, option) in options {
+        match option {
+            ColumnOption::NotNull => not_null = true,
+            ColumnOption::Null => not_null = false,
+            ColumnOption::Default(_) => is_autogenerated = true,
+            // identity columns are implicitly "NOT NULL", other generated columns are computed from a expression
+            ColumnOption::Generated {
+                generation_expr: None,
+                ..
+            }
+            | ColumnOption::Identity(_) => {
+                not_null = true;
+                is_autogenerated = true;
+            }
+            ColumnOption::Generated { .. } => is_autogenerated = true,
+            // like "AUTOINCREMENT" (sqlite) or "AUTO_INCREMENT" (mysql)
+            ColumnOption::DialectSpecific(tokens)
+                if tokens.iter().any(|token| {
+                    let token = token.to_string().to_lowercase();
+                    token == "autoincrement" || token == "auto_increment"
+                }) =>
+            {
+                is_autogenerated = true
+            }
+            ColumnOption::Comment(value) => comment = Some(value),
+            ColumnOption::PrimaryKey(_) => {
+                table.primary_keys = vec![name.to_string()];
+                table.primary_key_constraint = constraint;
+            }
+            ColumnOption::ForeignKey(foreign_key) => table.foreign_keys.push(MigrationForeignKey {
+                constraint,
+                column: name.to_string(),
+                foreign_table: object_name(&foreign_key.foreign_table),
+            }),
+            _ => (),
+        }
+    }
+
+    if let Some(column) = find_column(table, name) {
+        column.sql_type = sql_type;
+        column.not_null = not_null;
+        column.is_autogenerated = is_autogenerated;
+        // keep the comment from "COMMENT ON", if not set in the definition
+        column.comment = comment.or(column.comment.take());
+    }
+}
+
+/// Add a table constraint, only primary keys and single column foreign keys are used
+fn add_constraint(table: &mut MigrationTable, constraint: TableConstraint) {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => {
+            table.primary_keys = primary_key
+                .columns
+                .iter()
+                .filter_map(|column| match &column.column.expr {
+                    Expr::Identifier(ident) => Some(ident_name(ident)),
+                    _ => None,
+                })
+                .collect();
+            table.primary_key_constraint = primary_key.name.as_ref().map(ident_name);
+        }
+        TableConstraint::ForeignKey(foreign_key) => {
+            // "joinable!" only supports single column foreign keys
+            if let [column] = foreign_key.columns.as_slice() {
+                table.foreign_keys.push(MigrationForeignKey {
+                    constraint: foreign_key.name.as_ref().map(ident_name),
+                    column: ident_name(column),
+                    foreign_table: object_name(&foreign_key.foreign_table),
+                });
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Rename the column `old_name`, including in the primary keys and foreign keys
+fn rename_column(table: &mut MigrationTable, old_name: &str, new_name: String) {
+    if let Some(column) = find_column(table, old_name) {
+        column.name = new_name.clone();
+    }
+
+    for primary_key in table.primary_keys.iter_mut() {
+        if primary_key == old_name {
+            *primary_key = new_name.clone();
+        }
+    }
+
+    for foreign_key in table.foreign_keys.iter_mut() {
+        if foreign_key.column == old_name {
+            foreign_key.column = new_name.clone();
+        }
+    }
+}
+
+fn find_table<'a>(tables: &'a mut [MigrationTable], name: &str) -> Option<&'a mut MigrationTable> {
+    tables.iter_mut().find(|table| table.name == name)
+}
+
+fn find_column<'a>(table: &'a mut MigrationTable, name: &str) -> Option<&'a mut MigrationColumn> {
+    table.columns.iter_mut().find(|column| column.name == name)
+}
+
+/// Get the name of a identifier like the database does, unquoted identifiers are case-insensitive
+fn ident_name(ident: &sqlparser::ast::Ident) -> String {
+    match ident.quote_style {
+        Some(_) => ident.value.clone(),
+        None => ident.value.to_lowercase(),
+    }
+}
+
+/// Get all parts of a object name like "schema.table"
+fn object_name_parts(name: &ObjectName) -> Vec<String> {
+    name.0
+        .iter()
+        .filter_map(|part| part.as_ident())
+        .map(ident_name)
+        .collect()
+}
+
+/// Get the name of a table, without the schema (like "public.users")
+fn object_name(name: &ObjectName) -> String {
+    object_name_parts(name).pop().unwrap_or_default()
+}
+
+/// Get the schema of a table name, if qualified with one (like "public" in "public.users")
+fn object_schema(name: &ObjectName) -> Option<String> {
+    let mut parts = object_name_parts(name);
+    parts.pop();
+
+    parts.pop()
+}
+
+/// Convert a sql data type to the diesel type, also returns whether the type is a postgres "SERIAL" type
+fn data_type_to_column_type(data_type: &DataType) -> (ParsedColumnType, bool) {
+    if let DataType::Array(
+        ArrayElemTypeDef::SquareBracket(inner, _)
+        | ArrayElemTypeDef::AngleBracket(inner)
+        | ArrayElemTypeDef::Parenthesis(inner)
+        | ArrayElemTypeDef::Qualified(inner, _),
+    ) = data_type
+    {
+        // postgres array elements are always nullable, like "diesel print-schema" generates it
+        let (inner, _) = data_type_to_column_type(inner);
+
+        return (
+            ParsedColumnType::Array(Box::new(ParsedColumnType::Nullable(Box::new(inner)))),
+            false,
+        );
+    }
+
+    let type_name = data_type.to_string().to_lowercase();
+    // remove arguments like in "VARCHAR(255)" and normalize the whitespace of multi-word types
+    let type_name = type_name
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let is_serial = matches!(
+        type_name.as_str(),
+        "serial" | "serial4" | "bigserial" | "serial8" | "smallserial" | "serial2"
+    );
+
+    let diesel_type = match type_name.as_str() {
+        "tinyint" => "TinyInt",
+        "smallint" | "int2" | "smallserial" | "serial2" => "Int2",
+        "integer" | "int" | "int4" | "mediumint" | "serial" | "serial4" => "Int4",
+        "bigint" | "int8" | "bigserial" | "serial8" => "Int8",
+        "boolean" | "bool" => "Bool",
+        "real" | "float4" => "Float4",
+        "double precision" | "double" | "float8" => "Float8",
+        "numeric" | "decimal" | "dec" => "Numeric",
+        "character varying" | "varchar" | "nvarchar" => "Varchar",
+        "character" | "char" => "Bpchar",
+        "timestamp" | "timestamp without time zone" | "datetime" => "Timestamp",
+        "timestamptz" | "timestamp with time zone" => "Timestamptz",
+        "time" | "time without time zone" => "Time",
+        "bytea" => "Bytea",
+        // other types are used as-is, so they are resolved like any other type
+        type_name => {
+            use heck::ToPascalCase;
+
+            return (
+                ParsedColumnType::Base(parser::to_rust_ident(&type_name.to_pascal_case())),
+                false,
+            );
+        }
+    };
+
+    (
+        ParsedColumnType::Base(Ident::new(diesel_type, proc_macro2::Span::call_site())),
+        is_serial,
+    )
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+/// Words which are the same in singular and plural
+const UNCOUNTABLE_WORDS: &[&str] = &[
+    "data",
+    "equipment",
+    "feedback",
+    "information",
+    "metadata",
+    "news",
+    "series",
+    "species",
+];
+
+/// Plurals which are not covered by the suffix rules in [singularize_word], with their singular
+const IRREGULAR_WORDS: &[(&str, &str)] = &[
+    ("aliases", "alias"),
+    ("analyses", "analysis"),
+    ("buses", "bus"),
+    ("children", "child"),
+    ("feet", "foot"),
+    ("geese", "goose"),
+    ("halves", "half"),
+    ("indices", "index"),
+    ("knives", "knife"),
+    ("lives", "life"),
+    ("matrices", "matrix"),
+    ("men", "man"),
+    ("mice", "mouse"),
+    ("movies", "movie"),
+    ("people", "person"),
+    ("shelves", "shelf"),
+    ("statuses", "status"),
+    ("teeth", "tooth"),
+    ("vertices", "vertex"),
+    ("viruses", "virus"),
+    ("wives", "wife"),
+    ("wolves", "wolf"),
+    ("women", "woman"),
+];
+
+/// Singularize a table name like "user_roles" to "user_role", only the last word is singularized
+/// `overrides` map a plural to its singular and are matched against the whole name first, then against the last word
+pub fn singularize(name: &str, overrides: &HashMap<&str, &str>) -> String {
+    if let Some(singular) = overrides.get(name) {
+        return singular.to_string();
+    }
+
+    let (prefix, word) = match name.rfind('_') {
+        Some(index) => name.split_at(index + 1),
+        None => ("", name),
+    };
+
+    let singular = match overrides.get(word) {
+        Some(singular) => singular.to_string(),
+        None => singularize_word(word),
+    };
+
+    format!("{prefix}{singular}")
+}
+
+/// Singularize a single english word with the built-in rules
+fn singularize_word(word: &str) -> String {
+    let lowercase = word.to_lowercase();
+
+    if UNCOUNTABLE_WORDS.contains(&lowercase.as_str()) {
+        return word.to_string();
+    }
+
+    if let Some((_, singular)) = IRREGULAR_WORDS
+        .iter()
+        .find(|(plural, _)| *plural == lowercase)
+    {
+        return singular.to_string();
+    }
+
+    // (plural suffix, singular suffix)
+    const SUFFIX_RULES: &[(&str, &str)] = &[
+        ("ies", "y"),
+        ("sses", "ss"),
+        ("shes", "sh"),
+        ("ches", "ch"),
+        ("xes", "x"),
+        ("zzes", "zz"),
+    ];
+
+    for (plural, singular) in SUFFIX_RULES {
+        if lowercase.len() > plural.len() && lowercase.ends_with(plural) {
+            return format!("{}{singular}", &word[..word.len() - plural.len()]);
+        }
+    }
+
+    // words like "address", "status" or "analysis" are already singular
+    if ["ss", "us", "is"].iter().any(|v| lowercase.ends_with(v)) {
+        return word.to_string();
+    }
+
+    match word.strip_suffix(['s', 'S']) {
+        Some(singular) if !singular.is_empty() => singular.to_string(),
+        _ => word.to_string(),
+    }
+}
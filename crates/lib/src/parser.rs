@@ -1,31 +1,277 @@
-use heck::ToPascalCase;
+use syn::spanned::Spanned;
 use syn::Ident;
 use syn::Item::Macro;
 
-use crate::{code, Error, GenerationConfig, Result};
+use crate::{
+    code, DatabaseBackend, DateTimeBackend, Error, GenerationConfig, Result, TableOptions, Warning,
+};
 
 pub const FILE_SIGNATURE: &str = "/* This file is generated and managed by dsync */";
 
-// TODO: handle postgres array types
-// TODO: handle postgres tuple/record types
+/// Parsed representation of a diesel column type, like `Array<Nullable<Text>>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsedColumnType {
+    /// A plain diesel sql type, like `Text` or `Int4`
+    Base(Ident),
+    /// Diesel `Nullable<T>`
+    Nullable(Box<ParsedColumnType>),
+    /// Postgres `Array<T>`
+    Array(Box<ParsedColumnType>),
+    /// Mysql `Unsigned<T>`, `Unsigned<Nullable<T>>` is parsed as `Nullable<Unsigned<T>>`
+    Unsigned(Box<ParsedColumnType>),
+    /// Postgres `Record<(A, B)>`, with the types of all elements
+    Record(Vec<ParsedColumnType>),
+}
+
+impl ParsedColumnType {
+    /// Get whether the type is nullable at the top-level
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, ParsedColumnType::Nullable(_))
+    }
+
+    /// Get whether the type is or contains a postgres `Record`, which diesel can only read and not write
+    pub fn contains_record(&self) -> bool {
+        match self {
+            ParsedColumnType::Base(_) => false,
+            ParsedColumnType::Nullable(inner)
+            | ParsedColumnType::Array(inner)
+            | ParsedColumnType::Unsigned(inner) => inner.contains_record(),
+            ParsedColumnType::Record(_) => true,
+        }
+    }
+
+    /// Get the type without the top-level `Nullable` (if any)
+    pub fn strip_nullable(&self) -> &ParsedColumnType {
+        match self {
+            ParsedColumnType::Nullable(inner) => inner,
+            _ => self,
+        }
+    }
+
+    /// Convert the type tree into the rust type to use
+    /// Custom sql types are only looked up in the given `schema`
+    pub fn to_rust_type(
+        &self,
+        config: &GenerationConfig,
+        sql_types: &[ParsedSqlType],
+        schema: Option<&Ident>,
+    ) -> Result<String> {
+        Ok(match self {
+            ParsedColumnType::Base(ident) => {
+                if let Some(ty) = config.sql_type_override(&ident.to_string()) {
+                    ty.to_string()
+                } else if sql_types
+                    .iter()
+                    .any(|v| &v.name == ident && v.schema.as_ref() == schema)
+                {
+                    format!(
+                        "{}types::{ident}",
+                        path_with_schema(&config.model_path, schema)
+                    )
+                } else {
+                    match schema_type_to_rust_type(ident.to_string(), config)
+                        .map_err(|err| err.with_span(ident.span()))?
+                    {
+                        Some(ty) => ty,
+                        // return the schema type if no type is found (this means generation is broken for this particular schema)
+                        None => format!(
+                            "{}sql_types::{ident}",
+                            path_with_schema(&config.schema_path, schema)
+                        ),
+                    }
+                }
+            }
+            ParsedColumnType::Nullable(inner) => {
+                format!("Option<{}>", inner.to_rust_type(config, sql_types, schema)?)
+            }
+            ParsedColumnType::Array(inner) => {
+                format!("Vec<{}>", inner.to_rust_type(config, sql_types, schema)?)
+            }
+            ParsedColumnType::Unsigned(inner) => {
+                let unsupported = |ident: &Ident| {
+                    Error::unsupported_type(format!(
+                        "\"Unsigned\" is only supported for integer types, got \"{ident}\""
+                    ))
+                    .with_span(ident.span())
+                };
+
+                let ParsedColumnType::Base(ident) = inner.as_ref() else {
+                    return Err(match inner.base_idents().first() {
+                        Some(ident) => unsupported(ident),
+                        None => Error::unsupported_type(
+                            "\"Unsigned\" is only supported for integer types",
+                        ),
+                    });
+                };
+
+                let ty = inner.to_rust_type(config, sql_types, schema)?;
+
+                // overridden types are used as-is
+                if config.sql_type_override(&ident.to_string()).is_some() {
+                    ty
+                } else {
+                    match ty.as_str() {
+                        "i8" => "u8",
+                        "i16" => "u16",
+                        "i32" => "u32",
+                        "i64" => "u64",
+                        _ => return Err(unsupported(ident)),
+                    }
+                    .to_string()
+                }
+            }
+            ParsedColumnType::Record(elements) => {
+                let elements = elements
+                    .iter()
+                    .map(|element| element.to_rust_type(config, sql_types, schema))
+                    .collect::<Result<Vec<String>>>()?;
+
+                // a single element tuple needs a trailing comma
+                if elements.len() == 1 {
+                    format!("({},)", elements[0])
+                } else {
+                    format!("({})", elements.join(", "))
+                }
+            }
+        })
+    }
+
+    /// Get all innermost base types, multiple for a `Record`
+    pub fn base_idents(&self) -> Vec<&Ident> {
+        match self {
+            ParsedColumnType::Base(ident) => vec![ident],
+            ParsedColumnType::Nullable(inner)
+            | ParsedColumnType::Array(inner)
+            | ParsedColumnType::Unsigned(inner) => inner.base_idents(),
+            ParsedColumnType::Record(elements) => elements
+                .iter()
+                .flat_map(ParsedColumnType::base_idents)
+                .collect(),
+        }
+    }
+
+    /// Get all base types which are neither a known type, a overridden type nor a custom sql type in the given `schema`
+    pub fn unknown_types(
+        &self,
+        config: &GenerationConfig,
+        sql_types: &[ParsedSqlType],
+        schema: Option<&Ident>,
+    ) -> Vec<&Ident> {
+        match self {
+            ParsedColumnType::Base(ident) => {
+                let is_sql_type = sql_types
+                    .iter()
+                    .any(|v| &v.name == ident && v.schema.as_ref() == schema);
+                let is_overridden = config.sql_type_override(&ident.to_string()).is_some();
+
+                // unsupported types are a error and not a warning, so they are not unknown
+                if is_sql_type
+                    || is_overridden
+                    || !matches!(
+                        schema_type_to_rust_type(ident.to_string(), config),
+                        Ok(None)
+                    )
+                {
+                    vec![]
+                } else {
+                    vec![ident]
+                }
+            }
+            ParsedColumnType::Nullable(inner)
+            | ParsedColumnType::Array(inner)
+            | ParsedColumnType::Unsigned(inner) => inner.unknown_types(config, sql_types, schema),
+            ParsedColumnType::Record(elements) => elements
+                .iter()
+                .flat_map(|element| element.unknown_types(config, sql_types, schema))
+                .collect(),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ParsedColumnMacro {
-    /// Rust type to use
+    /// Rust type to use (without the top-level [Option] for nullable columns)
     pub ty: String,
+    /// Parsed diesel type of the column
+    pub sql_type: ParsedColumnType,
     /// Rust name of the column
     pub name: Ident,
     pub is_nullable: bool,
-    pub is_unsigned: bool,
+    /// Whether `ty` is set from a column type override
+    pub is_type_overridden: bool,
+    /// Why the column is generated by the database, if marked by the input (like a sqlite rowid alias) or inferred
+    pub autogenerated: Option<AutogeneratedReason>,
+    /// Comment of the column in the database, if known
+    pub comment: Option<String>,
     /// Actual table column name
     pub column_name: String,
 }
 
+impl ParsedColumnMacro {
+    /// Create a new column, resolving the rust type from the column type overrides or from `sql_type`
+    pub(crate) fn new(
+        table_name: Option<&Ident>,
+        name: Ident,
+        column_name: String,
+        sql_type: ParsedColumnType,
+        config: &GenerationConfig,
+        sql_types: &[ParsedSqlType],
+        schema: Option<&Ident>,
+    ) -> Result<Self> {
+        let column_type_override = table_name
+            .and_then(|table| config.column_type_override(&table.to_string(), &name.to_string()));
+
+        Ok(Self {
+            ty: match column_type_override {
+                Some(ty) => ty.to_string(),
+                None => sql_type
+                    .strip_nullable()
+                    .to_rust_type(config, sql_types, schema)?,
+            },
+            is_nullable: sql_type.is_nullable(),
+            is_type_overridden: column_type_override.is_some(),
+            autogenerated: None,
+            comment: None,
+            name,
+            sql_type,
+            column_name,
+        })
+    }
+}
+
+/// Why a column is treated as autogenerated without being listed in the autogenerated columns option
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutogeneratedReason {
+    /// The input marks the column as set by the database, like a `DEFAULT` in a migration
+    DatabaseDefault,
+    /// The column has a serial type (`Serial`, `BigSerial` or `SmallSerial`)
+    Serial,
+    /// The column is the single integer primary key on sqlite, which is a alias for the rowid
+    RowidAlias,
+    /// The column name matches the contained pattern from the autogenerated patterns option
+    Pattern(String),
+}
+
+impl std::fmt::Display for AutogeneratedReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutogeneratedReason::DatabaseDefault => {
+                write!(f, "has a default value in the database")
+            }
+            AutogeneratedReason::Serial => write!(f, "has a serial type"),
+            AutogeneratedReason::RowidAlias => write!(f, "is a alias for the sqlite rowid"),
+            AutogeneratedReason::Pattern(pattern) => write!(f, "matches pattern \"{pattern}\""),
+        }
+    }
+}
+
 /// Struct for a parsed diesel schema
 #[derive(Debug, Clone)]
 pub struct ParsedTableMacro {
     /// Name of the table
     pub name: Ident,
+    /// Name of the schema module the table is in (`pub mod schema { diesel::table! {} }`), if any
+    pub schema: Option<Ident>,
     /// Struct name to use for generation
     pub struct_name: String,
     /// All parsed columns
@@ -37,6 +283,10 @@ pub struct ParsedTableMacro {
         ForeignTableName,
         JoinColumn, /* this is the column from this table which maps to the foreign table's primary key*/
     )>,
+    /// All tables with a foreign key relation to this table (child_table_name, child_join_column)
+    pub children: Vec<(ForeignTableName, JoinColumn)>,
+    /// All many-to-many relations through a join table to other tables
+    pub many_to_many: Vec<ParsedManyToMany>,
     /// Final Generated code
     pub generated_code: Option<String>,
 }
@@ -48,11 +298,85 @@ impl ParsedTableMacro {
             .map(|i| i.to_string())
             .collect()
     }
+
+    /// Get the diesel schema path for this table's schema module, like "crate::schema::myschema::"
+    pub fn schema_path(&self, config: &GenerationConfig) -> String {
+        path_with_schema(&config.schema_path, self.schema.as_ref())
+    }
+
+    /// Get the model path for this table's schema module, like "crate::models::myschema::"
+    pub fn model_path(&self, config: &GenerationConfig) -> String {
+        path_with_schema(&config.model_path, self.schema.as_ref())
+    }
+
+    /// Get whether any column uses a date or time type of the configured [DateTimeBackend]
+    pub fn uses_datetime_types(&self, config: &GenerationConfig) -> bool {
+        self.columns
+            .iter()
+            .filter(|column| !column.is_type_overridden)
+            .flat_map(|column| column.sql_type.base_idents())
+            .any(|ident| {
+                let base_type = ident.to_string();
+                // ranges of date and time types also use the backend
+                let element_type = range_element_type(&base_type).unwrap_or(&base_type);
+
+                config.sql_type_override(&base_type).is_none()
+                    && config.sql_type_override(element_type).is_none()
+                    && datetime_type_to_rust_type(element_type, config.datetime_backend).is_some()
+            })
+    }
+}
+
+/// Convert a database name to a valid rust identifier, like "diesel print-schema" does
+/// keywords get a "_" suffix and invalid characters are replaced with "_"
+pub(crate) fn to_rust_ident(name: &str) -> Ident {
+    if let Ok(ident) = syn::parse_str::<Ident>(name) {
+        return ident;
+    }
+
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if !sanitized.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        sanitized.insert(0, '_');
+    }
+
+    // keywords are only invalid as-is
+    if syn::parse_str::<Ident>(&sanitized).is_err() {
+        sanitized.push('_');
+    }
+
+    Ident::new(&sanitized, proc_macro2::Span::call_site())
+}
+
+/// Append the schema module (if any) to a path like "crate::schema::"
+pub fn path_with_schema(path: &str, schema: Option<&Ident>) -> String {
+    match schema {
+        Some(schema) => format!("{path}{schema}::"),
+        None => path.to_string(),
+    }
 }
 
 type ForeignTableName = Ident;
 type JoinColumn = String;
 
+/// A many-to-many relation through a join table, which has a primary key of two foreign keys
+#[derive(Debug, Clone)]
+pub struct ParsedManyToMany {
+    /// Name of the join table
+    pub join_table: Ident,
+    /// Column of the join table which references this table
+    pub column: JoinColumn,
+    /// Name of the table on the other side of the relation
+    pub other_table: ForeignTableName,
+    /// Column of the join table which references the other table
+    pub other_column: JoinColumn,
+    /// Rust type of `other_column`
+    pub other_column_type: String,
+}
+
 /// Parsed representation of the `diesel::joinable!` macro
 #[derive(Debug, Clone)]
 pub struct ParsedJoinMacro {
@@ -64,67 +388,455 @@ pub struct ParsedJoinMacro {
     pub table1_columns: String,
 }
 
+/// Parsed representation of a custom sql type from the diesel `sql_types` module
+/// ```rs
+/// #[derive(diesel::sql_types::SqlType)]
+/// #[diesel(postgres_type(name = "mood"))]
+/// pub struct Mood;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParsedSqlType {
+    /// Rust name of the sql type, like `Mood`
+    pub name: Ident,
+    /// Name of the schema module the type is in, if any
+    pub schema: Option<Ident>,
+    /// Name of the type in the database, like `mood`
+    pub db_name: Option<String>,
+    /// Diesel backend the type is for
+    pub backend: SqlTypeBackend,
+}
+
+/// Diesel backends which can have custom sql types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlTypeBackend {
+    Postgres,
+    Mysql,
+}
+
+impl SqlTypeBackend {
+    /// Get the diesel backend type path
+    pub fn backend_path(&self) -> &'static str {
+        match self {
+            SqlTypeBackend::Postgres => "diesel::pg::Pg",
+            SqlTypeBackend::Mysql => "diesel::mysql::Mysql",
+        }
+    }
+
+    /// Get the diesel raw value type path
+    pub fn value_path(&self) -> &'static str {
+        match self {
+            SqlTypeBackend::Postgres => "diesel::pg::PgValue",
+            SqlTypeBackend::Mysql => "diesel::mysql::MysqlValue",
+        }
+    }
+}
+
+/// Struct for a parsed diesel schema file
+#[derive(Debug, Clone)]
+pub struct ParsedSchema {
+    /// All parsed tables (excluding ignored ones)
+    pub tables: Vec<ParsedTableMacro>,
+    /// All parsed custom sql types
+    pub sql_types: Vec<ParsedSqlType>,
+    /// Final Generated code for the custom sql types, per schema module (`None` being the top-level)
+    pub sql_types_code: Vec<(Option<Ident>, String)>,
+    /// All non-fatal warnings found while parsing and generating
+    pub warnings: Vec<Warning>,
+}
+
 /// Try to parse a diesel schema file
+/// Errors will have the source snippet attached, if they have a location
 pub fn parse_and_generate_code(
     schema_file_contents: String,
     config: &GenerationConfig,
-) -> Result<Vec<ParsedTableMacro>> {
-    let schema_file = syn::parse_file(&schema_file_contents).unwrap();
+) -> Result<ParsedSchema> {
+    parse_and_generate_code_inner(&schema_file_contents, config)
+        .map_err(|err| err.with_source(&schema_file_contents))
+}
+
+fn parse_and_generate_code_inner(
+    schema_file_contents: &str,
+    config: &GenerationConfig,
+) -> Result<ParsedSchema> {
+    let schema_file = syn::parse_file(schema_file_contents).map_err(|err| {
+        Error::unsupported_schema_format(format!("Could not parse schema file: {err}"))
+            .with_span(err.span())
+    })?;
 
     let mut tables: Vec<ParsedTableMacro> = vec![];
+    let mut sql_types: Vec<ParsedSqlType> = vec![];
+    let mut joins: Vec<(Option<Ident>, ParsedJoinMacro)> = vec![];
+    let mut warnings: Vec<Warning> = vec![];
 
-    for item in schema_file.items {
-        if let Macro(macro_item) = item {
-            let macro_identifier = macro_item
-                .mac
-                .path
-                .segments
-                .last()
-                .ok_or(Error::other("could not read identifier for macro"))?
-                .ident
-                .to_string();
-
-            match macro_identifier.as_str() {
-                "table" => {
-                    let parsed_table = handle_table_macro(macro_item, config)?;
-
-                    // make sure the table isn't ignored
-                    let table_options = config.table(parsed_table.name.to_string().as_str());
-                    if !table_options.get_ignore() {
-                        tables.push(parsed_table);
-                    }
+    // collect custom sql types first, as they are required to be known for the columns
+    collect_sql_types(&schema_file.items, None, &mut sql_types)?;
+
+    handle_items(
+        schema_file.items,
+        None,
+        config,
+        &sql_types,
+        &mut tables,
+        &mut joins,
+        &mut warnings,
+    )?;
+
+    generate_schema(tables, sql_types, joins, warnings, config)
+}
+
+/// Add the many-to-many relations of all join tables to both tables of the relation
+///
+/// A join table is a table whose primary key consists of exactly two columns which are foreign keys to two different tables
+fn resolve_many_to_many(tables: &mut [ParsedTableMacro]) {
+    let mut relations: Vec<(Option<Ident>, ForeignTableName, ParsedManyToMany)> = vec![];
+
+    for table in tables.iter() {
+        let [first, second] = table.primary_key_columns.as_slice() else {
+            continue;
+        };
+        let foreign_table = |column: &Ident| {
+            table
+                .foreign_keys
+                .iter()
+                .find(|(_, join_column)| column == join_column.as_str())
+                .map(|(foreign_table_name, _)| foreign_table_name.clone())
+        };
+        let column_type = |column: &Ident| {
+            table
+                .columns
+                .iter()
+                .find(|it| &it.name == column)
+                .map(|it| it.ty.clone())
+        };
+
+        let (Some(first_table), Some(second_table)) = (foreign_table(first), foreign_table(second))
+        else {
+            continue;
+        };
+        // a self-referencing relation cannot be expressed with "joinable!"
+        if first_table == second_table {
+            continue;
+        }
+        let (Some(first_type), Some(second_type)) = (column_type(first), column_type(second))
+        else {
+            continue;
+        };
+
+        relations.push((
+            table.schema.clone(),
+            first_table.clone(),
+            ParsedManyToMany {
+                join_table: table.name.clone(),
+                column: first.to_string(),
+                other_table: second_table.clone(),
+                other_column: second.to_string(),
+                other_column_type: second_type,
+            },
+        ));
+        relations.push((
+            table.schema.clone(),
+            second_table,
+            ParsedManyToMany {
+                join_table: table.name.clone(),
+                column: second.to_string(),
+                other_table: first_table,
+                other_column: first.to_string(),
+                other_column_type: first_type,
+            },
+        ));
+    }
+
+    for (schema, table_name, relation) in relations {
+        if let Some(table) = tables
+            .iter_mut()
+            .find(|table| table.schema == schema && table.name == table_name)
+        {
+            table.many_to_many.push(relation);
+        }
+    }
+}
+
+/// Resolve the joins and generate the code for all parsed tables and sql types, independent of the input format
+pub(crate) fn generate_schema(
+    mut tables: Vec<ParsedTableMacro>,
+    sql_types: Vec<ParsedSqlType>,
+    joins: Vec<(Option<Ident>, ParsedJoinMacro)>,
+    mut warnings: Vec<Warning>,
+    config: &GenerationConfig,
+) -> Result<ParsedSchema> {
+    // resolve joins after all tables are known, so that it can be checked that both tables exist
+    for (schema, join) in joins {
+        let has_foreign_table = tables
+            .iter()
+            .any(|table| table.schema == schema && table.name == join.table2);
+        let table = tables
+            .iter_mut()
+            .find(|table| table.schema == schema && table.name == join.table1);
+
+        match table {
+            Some(table) if has_foreign_table => {
+                table
+                    .foreign_keys
+                    .push((join.table2.clone(), join.table1_columns.clone()));
+
+                // the reverse direction, so that the foreign table can load its children
+                if let Some(foreign_table) = tables
+                    .iter_mut()
+                    .find(|table| table.schema == schema && table.name == join.table2)
+                {
+                    foreign_table
+                        .children
+                        .push((join.table1.clone(), join.table1_columns.clone()));
                 }
-                "joinable" => {
-                    let parsed_join = handle_joinable_macro(macro_item)?;
-
-                    for table in tables.iter_mut() {
-                        if parsed_join
-                            .table1
-                            .to_string()
-                            .eq(table.name.to_string().as_str())
-                        {
-                            table.foreign_keys.push((
-                                parsed_join.table2.clone(),
-                                parsed_join.table1_columns.clone(),
-                            ));
-                            break;
+            }
+            _ => warnings.push(Warning::DanglingJoinable {
+                table: join.table1.to_string(),
+                foreign_table: join.table2.to_string(),
+            }),
+        }
+    }
+
+    resolve_many_to_many(&mut tables);
+
+    check_autogenerated_columns(&tables, config, &mut warnings);
+
+    for table in tables.iter_mut() {
+        table.generated_code = Some(code::generate_for_table(table.clone(), config)?);
+    }
+
+    // generate the sql types code per schema module, in order of appearance
+    let mut sql_types_code: Vec<(Option<Ident>, String)> = vec![];
+    for sql_type in sql_types.iter() {
+        if sql_types_code
+            .iter()
+            .any(|(schema, _)| schema == &sql_type.schema)
+        {
+            continue;
+        }
+
+        let schema_sql_types: Vec<ParsedSqlType> = sql_types
+            .iter()
+            .filter(|v| v.schema == sql_type.schema)
+            .cloned()
+            .collect();
+
+        sql_types_code.push((
+            sql_type.schema.clone(),
+            code::generate_sql_types(&schema_sql_types, &tables, config),
+        ));
+    }
+
+    Ok(ParsedSchema {
+        tables,
+        sql_types,
+        sql_types_code,
+        warnings,
+    })
+}
+
+/// Check that all columns set as autogenerated exist
+fn check_autogenerated_columns(
+    tables: &[ParsedTableMacro],
+    config: &GenerationConfig,
+    warnings: &mut Vec<Warning>,
+) {
+    let has_column =
+        |table: &ParsedTableMacro, column: &str| table.columns.iter().any(|c| c.name == column);
+
+    // columns from the default options only need to exist in any table
+    for column in config.default_table_options.get_autogenerated_columns() {
+        if !tables.iter().any(|table| has_column(table, column)) {
+            warnings.push(Warning::MissingAutogeneratedColumn {
+                table: None,
+                column: column.to_string(),
+            });
+        }
+    }
+
+    // columns from the table specific options need to exist in that table
+    for table in tables.iter() {
+        let table_name = table.name.to_string();
+        let Some(table_options) = config.table_specific(table_name.as_str()) else {
+            continue;
+        };
+
+        for column in table_options.get_autogenerated_columns() {
+            if !has_column(table, column) {
+                warnings.push(Warning::MissingAutogeneratedColumn {
+                    table: Some(table_name.clone()),
+                    column: column.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Recursively collect all custom sql types from "sql_types" modules, including the ones in schema modules
+fn collect_sql_types(
+    items: &[syn::Item],
+    schema: Option<&Ident>,
+    sql_types: &mut Vec<ParsedSqlType>,
+) -> Result<()> {
+    for item in items.iter() {
+        let syn::Item::Mod(mod_item) = item else {
+            continue;
+        };
+
+        if mod_item.ident == "sql_types" {
+            sql_types.append(&mut handle_sql_types_mod(mod_item, schema)?);
+        } else if schema.is_none() {
+            // only one level of schema modules is supported, like diesel generates it
+            if let Some((_, items)) = &mod_item.content {
+                collect_sql_types(items, Some(&mod_item.ident), sql_types)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle all items of a schema file or of a schema module (`pub mod schema { }`)
+fn handle_items(
+    items: Vec<syn::Item>,
+    schema: Option<&Ident>,
+    config: &GenerationConfig,
+    sql_types: &[ParsedSqlType],
+    tables: &mut Vec<ParsedTableMacro>,
+    joins: &mut Vec<(Option<Ident>, ParsedJoinMacro)>,
+    warnings: &mut Vec<Warning>,
+) -> Result<()> {
+    for item in items {
+        match item {
+            Macro(macro_item) => {
+                let macro_identifier = macro_item
+                    .mac
+                    .path
+                    .segments
+                    .last()
+                    .ok_or(Error::other("could not read identifier for macro"))?
+                    .ident
+                    .to_string();
+
+                match macro_identifier.as_str() {
+                    "table" => {
+                        let parsed_table =
+                            handle_table_macro(macro_item, config, sql_types, schema, warnings)?;
+
+                        // make sure the table isn't ignored
+                        let table_options = config.table(parsed_table.name.to_string().as_str());
+                        if !table_options.get_ignore() {
+                            tables.push(parsed_table);
                         }
                     }
+                    "joinable" => {
+                        let parsed_join = handle_joinable_macro(macro_item)?;
+
+                        // joins are resolved once all tables are known
+                        joins.push((schema.cloned(), parsed_join));
+                    }
+                    _ => {}
+                };
+            }
+            syn::Item::Mod(mod_item) => {
+                // "sql_types" are handled in "collect_sql_types"
+                // and only one level of schema modules is supported, like diesel generates it
+                if mod_item.ident == "sql_types" || schema.is_some() {
+                    continue;
                 }
-                _ => {}
-            };
+
+                if let Some((_, items)) = mod_item.content {
+                    handle_items(
+                        items,
+                        Some(&mod_item.ident),
+                        config,
+                        sql_types,
+                        tables,
+                        joins,
+                        warnings,
+                    )?;
+                }
+            }
+            _ => {}
         }
     }
 
-    for table in tables.iter_mut() {
-        table.generated_code = Some(code::generate_for_table(table.clone(), config)?);
+    Ok(())
+}
+
+/// Try to parse all custom sql types in a diesel "sql_types" module
+fn handle_sql_types_mod(
+    mod_item: &syn::ItemMod,
+    schema: Option<&Ident>,
+) -> Result<Vec<ParsedSqlType>> {
+    let mut sql_types = vec![];
+
+    let Some((_, items)) = &mod_item.content else {
+        return Ok(sql_types);
+    };
+
+    for item in items {
+        let syn::Item::Struct(struct_item) = item else {
+            continue;
+        };
+
+        let mut backend_and_name: Option<(SqlTypeBackend, Option<String>)> = None;
+
+        for attr in struct_item.attrs.iter() {
+            if !attr.path.is_ident("diesel") {
+                continue;
+            }
+
+            // the tokens are like "(postgres_type(name = "mood"))"
+            for token in attr.tokens.clone().into_iter() {
+                let proc_macro2::TokenTree::Group(group) = token else {
+                    continue;
+                };
+
+                let mut inner = group.stream().into_iter();
+                while let Some(token) = inner.next() {
+                    let proc_macro2::TokenTree::Ident(ident) = token else {
+                        continue;
+                    };
+
+                    let backend = match ident.to_string().as_str() {
+                        "postgres_type" => SqlTypeBackend::Postgres,
+                        "mysql_type" => SqlTypeBackend::Mysql,
+                        _ => continue,
+                    };
+
+                    let db_name = match inner.next() {
+                        Some(proc_macro2::TokenTree::Group(group)) => {
+                            parse_diesel_attr_group_inner(group.stream())
+                                .filter(|(name, _)| name == "name")
+                                .map(|(_, value)| value)
+                        }
+                        _ => None,
+                    };
+
+                    backend_and_name = Some((backend, db_name));
+                }
+            }
+        }
+
+        // ignore structs that are not a sql type for a supported backend
+        let Some((backend, db_name)) = backend_and_name else {
+            continue;
+        };
+
+        sql_types.push(ParsedSqlType {
+            name: struct_item.ident.clone(),
+            schema: schema.cloned(),
+            db_name,
+            backend,
+        });
     }
 
-    Ok(tables)
+    Ok(sql_types)
 }
 
 /// Try to parse a "diesel::joinable!" macro
 fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro> {
+    let macro_span = macro_item.mac.path.span();
     let mut table1_name: Option<Ident> = None;
     let mut table2_name: Option<Ident> = None;
     let mut table2_join_column: Option<String> = None;
@@ -142,7 +854,8 @@ fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro>
                 if table1_name.is_none() || table2_name.is_none() {
                     return Err(Error::unsupported_schema_format(
                         "encountered join column group too early",
-                    ));
+                    )
+                    .with_span(group.span()));
                 } else {
                     table2_join_column = Some(group.stream().to_string());
                 }
@@ -152,15 +865,18 @@ fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro>
     }
 
     Ok(ParsedJoinMacro {
-        table1: table1_name.ok_or(Error::unsupported_schema_format(
-            "could not determine first join table name",
-        ))?,
-        table2: table2_name.ok_or(Error::unsupported_schema_format(
-            "could not determine second join table name",
-        ))?,
-        table1_columns: table2_join_column.ok_or(Error::unsupported_schema_format(
-            "could not determine join column name",
-        ))?,
+        table1: table1_name.ok_or_else(|| {
+            Error::unsupported_schema_format("could not determine first join table name")
+                .with_span(macro_span)
+        })?,
+        table2: table2_name.ok_or_else(|| {
+            Error::unsupported_schema_format("could not determine second join table name")
+                .with_span(macro_span)
+        })?,
+        table1_columns: table2_join_column.ok_or_else(|| {
+            Error::unsupported_schema_format("could not determine join column name")
+                .with_span(macro_span)
+        })?,
     })
 }
 
@@ -168,7 +884,11 @@ fn handle_joinable_macro(macro_item: syn::ItemMacro) -> Result<ParsedJoinMacro>
 fn handle_table_macro(
     macro_item: syn::ItemMacro,
     config: &GenerationConfig,
+    sql_types: &[ParsedSqlType],
+    schema: Option<&Ident>,
+    warnings: &mut Vec<Warning>,
 ) -> Result<ParsedTableMacro> {
+    let macro_span = macro_item.mac.path.span();
     let mut table_name_ident: Option<Ident> = None;
     let mut table_primary_key_idents: Vec<Ident> = vec![];
     let mut table_columns: Vec<ParsedColumnMacro> = vec![];
@@ -197,6 +917,8 @@ fn handle_table_macro(
                     skip_square_brackets = true;
                     continue;
                 }
+
+                // the "." in "schema.table" does not need handling, as the last ident is used as the table name
             }
             proc_macro2::TokenTree::Ident(ident) => {
                 // skip any "use" statements
@@ -227,14 +949,75 @@ fn handle_table_macro(
 
                     let mut rust_column_name: Option<Ident> = None;
                     let mut actual_column_name: Option<String> = None;
-                    let mut column_type: Option<Ident> = None;
-                    let mut column_nullable: bool = false;
-                    let mut column_unsigned: bool = false;
+                    // "Some" once the "->" has been encountered
+                    let mut column_type_tokens: Option<proc_macro2::TokenStream> = None;
+                    let mut had_arrow_start = false;
+                    // depth of "<>" in the type, so that "," in generics does not end the column
+                    let mut type_generics_depth: usize = 0;
                     let mut had_hashtag = false;
 
                     for column_tokens in group.stream().into_iter() {
                         let had_hashtag_last = had_hashtag;
                         had_hashtag = false;
+
+                        // collect all type tokens until the end of the column definition
+                        if let Some(type_tokens) = column_type_tokens.as_mut() {
+                            let mut is_end = false;
+
+                            if let proc_macro2::TokenTree::Punct(punct) = &column_tokens {
+                                match punct.as_char() {
+                                    '<' => type_generics_depth += 1,
+                                    '>' => {
+                                        type_generics_depth = type_generics_depth.saturating_sub(1)
+                                    }
+                                    ',' => is_end = type_generics_depth == 0,
+                                    _ => (),
+                                }
+                            }
+
+                            if !is_end {
+                                type_tokens.extend([column_tokens]);
+                                continue;
+                            }
+
+                            // end of column def!
+
+                            let rust_column_name_checked = rust_column_name.ok_or(
+                                Error::unsupported_schema_format("Invalid column name syntax"),
+                            )?;
+                            let column_name =
+                                actual_column_name.unwrap_or(rust_column_name_checked.to_string());
+                            let type_tokens = column_type_tokens.take().unwrap_or_default();
+                            // use the span of the whole type, because syn errors at the end of the input point to the macro call-site
+                            let type_span = type_tokens.span();
+                            let sql_type = parse_column_type(
+                                &syn::parse2::<syn::Type>(type_tokens).map_err(|err| {
+                                    Error::unsupported_schema_format(format!(
+                                        "Invalid column type syntax: {err}"
+                                    ))
+                                    .with_span(type_span)
+                                })?,
+                            )?;
+
+                            // add the column
+                            table_columns.push(ParsedColumnMacro::new(
+                                table_name_ident.as_ref(),
+                                rust_column_name_checked,
+                                column_name,
+                                sql_type,
+                                config,
+                                sql_types,
+                                schema,
+                            )?);
+
+                            // reset the properties
+                            rust_column_name = None;
+                            actual_column_name = None;
+                            had_arrow_start = false;
+
+                            continue;
+                        }
+
                         match column_tokens {
                             proc_macro2::TokenTree::Group(group) => {
                                 if had_hashtag_last {
@@ -252,12 +1035,11 @@ fn handle_table_macro(
                             proc_macro2::TokenTree::Ident(ident) => {
                                 if rust_column_name.is_none() {
                                     rust_column_name = Some(ident.clone());
-                                } else if ident.to_string().eq_ignore_ascii_case("Nullable") {
-                                    column_nullable = true;
-                                } else if ident.to_string().eq_ignore_ascii_case("Unsigned") {
-                                    column_unsigned = true;
                                 } else {
-                                    column_type = Some(ident.clone());
+                                    return Err(Error::unsupported_schema_format(
+                                        "Expected \"->\" after column name",
+                                    )
+                                    .with_span(ident.span()));
                                 }
                             }
                             proc_macro2::TokenTree::Punct(punct) => {
@@ -266,91 +1048,170 @@ fn handle_table_macro(
                                 if char == '#' {
                                     had_hashtag = true;
                                     continue;
-                                } else if char == '-' || char == '>' {
-                                    // nothing for arrow
-                                    continue;
-                                } else if char == ','
-                                    && rust_column_name.is_some()
-                                    && column_type.is_some()
-                                {
-                                    // end of column def!
-
-                                    let rust_column_name_checked = rust_column_name.ok_or(
-                                        Error::unsupported_schema_format(
-                                            "Invalid column name syntax",
-                                        ),
-                                    )?;
-                                    let column_name = actual_column_name
-                                        .unwrap_or(rust_column_name_checked.to_string());
-
-                                    // add the column
-                                    table_columns.push(ParsedColumnMacro {
-                                        name: rust_column_name_checked,
-                                        ty: schema_type_to_rust_type(
-                                            column_type
-                                                .ok_or(Error::unsupported_schema_format(
-                                                    "Invalid column type syntax",
-                                                ))?
-                                                .to_string(),
-                                            config,
-                                        )?,
-                                        is_nullable: column_nullable,
-                                        is_unsigned: column_unsigned,
-                                        column_name,
-                                    });
-
-                                    // reset the properties
-                                    rust_column_name = None;
-                                    actual_column_name = None;
-                                    column_type = None;
-                                    column_unsigned = false;
-                                    column_nullable = false;
+                                } else if char == '-' && rust_column_name.is_some() {
+                                    had_arrow_start = true;
+                                } else if char == '>' && had_arrow_start {
+                                    // everything after the arrow is the type
+                                    column_type_tokens = Some(proc_macro2::TokenStream::new());
+                                } else {
+                                    return Err(Error::unsupported_schema_format(
+                                        "Invalid punctuation in diesel table macro column",
+                                    )
+                                    .with_span(punct.span()));
                                 }
                             }
-                            _ => {
+                            token => {
                                 return Err(Error::unsupported_schema_format(
                                     "Invalid column definition token in diesel table macro",
-                                ))
+                                )
+                                .with_span(token.span()))
                             }
                         }
                     }
 
-                    if rust_column_name.is_some()
-                        || column_type.is_some()
-                        || column_nullable
-                        || column_unsigned
-                    {
+                    if rust_column_name.is_some() || column_type_tokens.is_some() {
                         // looks like a column was in the middle of being parsed, let's panic!
                         return Err(Error::unsupported_schema_format(
                             "It seems a column was partially defined",
-                        ));
+                        )
+                        .with_span(group.span_close()));
                     }
                 } else {
                     return Err(Error::unsupported_schema_format(
                         "Invalid delimiter in diesel table macro group",
-                    ));
+                    )
+                    .with_span(group.span()));
                 }
             }
             _ => {
                 return Err(Error::unsupported_schema_format(
                     "Invalid token tree item in diesel table macro",
-                ));
+                )
+                .with_span(item.span()));
             }
         }
     }
 
-    Ok(ParsedTableMacro {
-        name: table_name_ident
-            .clone()
-            .ok_or(Error::unsupported_schema_format(
-                "Could not extract table name from schema file",
-            ))?,
-        struct_name: table_name_ident.unwrap().to_string().to_pascal_case(),
+    let table_name = table_name_ident.ok_or_else(|| {
+        Error::unsupported_schema_format("Could not extract table name from schema file")
+            .with_span(macro_span)
+    })?;
+
+    Ok(finish_table(
+        table_name,
+        table_columns,
+        table_primary_key_idents,
+        config,
+        sql_types,
+        schema,
+        warnings,
+    ))
+}
+
+/// Create the [ParsedTableMacro] from all parsed parts of a table, independent of the input format
+/// Adds warnings for unknown types, removes primary keys which are not a column and infers autogenerated columns
+pub(crate) fn finish_table(
+    table_name: Ident,
+    mut table_columns: Vec<ParsedColumnMacro>,
+    mut table_primary_key_idents: Vec<Ident>,
+    config: &GenerationConfig,
+    sql_types: &[ParsedSqlType],
+    schema: Option<&Ident>,
+    warnings: &mut Vec<Warning>,
+) -> ParsedTableMacro {
+    for column in table_columns
+        .iter()
+        .filter(|column| !column.is_type_overridden)
+    {
+        for sql_type in column.sql_type.unknown_types(config, sql_types, schema) {
+            warnings.push(Warning::UnknownType {
+                table: table_name.to_string(),
+                column: column.name.to_string(),
+                sql_type: sql_type.to_string(),
+                fallback: format!(
+                    "{}sql_types::{sql_type}",
+                    path_with_schema(&config.schema_path, schema)
+                ),
+            });
+        }
+    }
+
+    // primary keys which are not a column cannot be used in the generated functions
+    table_primary_key_idents.retain(|pk| {
+        let exists = table_columns.iter().any(|column| &column.name == pk);
+
+        if !exists {
+            warnings.push(Warning::MissingPrimaryKeyColumn {
+                table: table_name.to_string(),
+                column: pk.to_string(),
+            });
+        }
+
+        exists
+    });
+
+    let table_options = config.table(&table_name.to_string());
+
+    if table_options.get_infer_autogenerated() {
+        infer_autogenerated_columns(
+            &mut table_columns,
+            &table_primary_key_idents,
+            &table_options,
+            config,
+        );
+    }
+
+    ParsedTableMacro {
+        struct_name: config.struct_name(&table_name.to_string()),
+        name: table_name,
+        schema: schema.cloned(),
         columns: table_columns,
         primary_key_columns: table_primary_key_idents,
         foreign_keys: vec![],
+        children: vec![],
+        many_to_many: vec![],
         generated_code: None,
-    })
+    }
+}
+
+/// Mark columns as autogenerated which are set by the database according to the schema
+/// Columns already marked by the input keep their reason
+fn infer_autogenerated_columns(
+    columns: &mut [ParsedColumnMacro],
+    primary_keys: &[Ident],
+    table_options: &TableOptions,
+    config: &GenerationConfig,
+) {
+    let is_sqlite = config.backend == Some(DatabaseBackend::Sqlite);
+
+    for column in columns
+        .iter_mut()
+        .filter(|column| column.autogenerated.is_none())
+    {
+        let base_type = match &column.sql_type {
+            ParsedColumnType::Base(ident) => ident.to_string().to_lowercase(),
+            _ => String::new(),
+        };
+        // diesel prints a "INTEGER PRIMARY KEY" as "Integer", which is a alias for the rowid if it is the only primary key
+        let is_rowid_alias = is_sqlite
+            && base_type == "integer"
+            && primary_keys.len() == 1
+            && primary_keys[0] == column.name;
+        let column_name = column.name.to_string();
+
+        column.autogenerated =
+            if matches!(base_type.as_str(), "serial" | "bigserial" | "smallserial") {
+                Some(AutogeneratedReason::Serial)
+            } else if is_rowid_alias {
+                Some(AutogeneratedReason::RowidAlias)
+            } else {
+                table_options
+                    .get_autogenerated_patterns()
+                    .iter()
+                    .find(|pattern| crate::glob_match(pattern, &column_name))
+                    .map(|pattern| AutogeneratedReason::Pattern(pattern.to_string()))
+            };
+    }
 }
 
 /// Parse a diesel schema attribute group
@@ -363,7 +1224,16 @@ fn parse_diesel_attr_group(group: &proc_macro2::Group) -> Option<(Ident, String)
         return None;
     }
 
-    let mut token_stream = group.stream().into_iter();
+    parse_diesel_attr_group_inner(group.stream())
+}
+
+/// Parse the inner part of a attribute like
+/// ```rs
+/// attr = value
+/// ```
+/// into (attr, value)
+fn parse_diesel_attr_group_inner(stream: proc_macro2::TokenStream) -> Option<(Ident, String)> {
+    let mut token_stream = stream.into_iter();
     let option_name = match token_stream.next()? {
         proc_macro2::TokenTree::Ident(ident) => ident,
         _ => return None,
@@ -393,6 +1263,82 @@ fn parse_diesel_attr_group(group: &proc_macro2::Group) -> Option<(Ident, String)
     Some((option_name, value))
 }
 
+/// Parse a diesel column type (like `Array<Nullable<Text>>`) into a [ParsedColumnType]
+fn parse_column_type(ty: &syn::Type) -> Result<ParsedColumnType> {
+    let path = match ty {
+        syn::Type::Path(type_path) => &type_path.path,
+        _ => {
+            return Err(
+                Error::unsupported_schema_format("Unsupported column type syntax")
+                    .with_span(ty.span()),
+            )
+        }
+    };
+
+    // only the last segment is used, so that things like "diesel::sql_types::Text" and "Text" are treated the same
+    let segment = path.segments.last().ok_or_else(|| {
+        Error::unsupported_schema_format("Empty column type path").with_span(ty.span())
+    })?;
+
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return Ok(ParsedColumnType::Base(segment.ident.clone()));
+    };
+
+    let mut args = args.args.iter();
+    let inner_ty = match (args.next(), args.next()) {
+        (Some(syn::GenericArgument::Type(inner)), None) => inner,
+        _ => {
+            return Err(Error::unsupported_schema_format(format!(
+                "Expected exactly one type argument for \"{}\"",
+                segment.ident
+            ))
+            .with_span(ty.span()))
+        }
+    };
+
+    let generic_name = segment.ident.to_string().to_lowercase();
+
+    // "Record" takes a tuple of types instead of a single type
+    if generic_name == "record" {
+        let syn::Type::Tuple(tuple) = inner_ty else {
+            return Err(Error::unsupported_schema_format(format!(
+                "Expected a tuple of types for \"{}\"",
+                segment.ident
+            ))
+            .with_span(inner_ty.span()));
+        };
+
+        return Ok(ParsedColumnType::Record(
+            tuple
+                .elems
+                .iter()
+                .map(parse_column_type)
+                .collect::<Result<Vec<ParsedColumnType>>>()?,
+        ));
+    }
+
+    let inner = Box::new(parse_column_type(inner_ty)?);
+
+    Ok(match generic_name.as_str() {
+        "nullable" => ParsedColumnType::Nullable(inner),
+        "array" => ParsedColumnType::Array(inner),
+        // normalize, so that nullability is always at the top-level
+        "unsigned" => match *inner {
+            ParsedColumnType::Nullable(inner) => {
+                ParsedColumnType::Nullable(Box::new(ParsedColumnType::Unsigned(inner)))
+            }
+            inner => ParsedColumnType::Unsigned(Box::new(inner)),
+        },
+        _ => {
+            return Err(Error::unsupported_type(format!(
+                "Unknown generic column type \"{}\"",
+                segment.ident
+            ))
+            .with_span(segment.ident.span()))
+        }
+    })
+}
+
 /// A function to translate diesel schema types into rust types
 ///
 /// reference: https://github.com/diesel-rs/diesel/blob/master/diesel/src/sql_types/mod.rs
@@ -400,88 +1346,161 @@ fn parse_diesel_attr_group(group: &proc_macro2::Group) -> Option<(Ident, String)
 ///
 /// The docs page for sql_types is comprehensive but it hides some alias types like Int4, Float8, etc.:
 /// https://docs.rs/diesel/latest/diesel/sql_types/index.html
-fn schema_type_to_rust_type(schema_type: String, config: &GenerationConfig) -> Result<String> {
-    Ok(match schema_type.to_lowercase().as_str() {
-        "unsigned" => return Err(Error::unsupported_type("Unsigned types are not yet supported, please open an issue if you need this feature!")), // TODO: deal with this later
-        "inet" => return Err(Error::unsupported_type("Unsigned types are not yet supported, please open an issue if you need this feature!")), // TODO: deal with this later
-        "cidr" => return Err(Error::unsupported_type("Unsigned types are not yet supported, please open an issue if you need this feature!")), // TODO: deal with this later
-
-        // boolean
-        "bool" => "bool",
-
-        // numbers
-        "tinyint" => "i8",
-        "smallint" => "i16",
-        "smallserial" => "i16",
-        "int2" => "i16",
-        "int4" => "i32",
-        "int4range" => "(std::collections::Bound<i32>, std::collections::Bound<i32>)",
-        "integer" => "i32",
-        "serial" => "i32",
-        "bigint" => "i64",
-        "bigserial" => "i64",
-        "int8" => "i64",
-        "int8range" => "(std::collections::Bound<i64>, std::collections::Bound<i64>)",
-        "float" => "f32",
-        "float4" => "f32",
-        "double" => "f64",
-        "float8" => "f64",
-        "numeric" => "bigdecimal::BigDecimal",
-        "numrange" => "(std::collections::Bound<bigdecimal::BigDecimal>, std::collections::Bound<bigdecimal::BigDecimal>)",
-        "decimal" => "bigdecimal::BigDecimal",
-
-        // string
-        "text" => "String",
-        "varchar" => "String",
-        "bpchar" => "String",
-        "char" => "String",
-        "tinytext" => "String",
-        "mediumtext" => "String",
-        "longtext" => "String",
-
-        // bytes
-        "binary" => "Vec<u8>",
-        "bytea" => "Vec<u8>",
-        "tinyblob" => "Vec<u8>",
-        "blob" => "Vec<u8>",
-        "mediumblob" => "Vec<u8>",
-        "longblob" => "Vec<u8>",
-        "varbinary" => "Vec<u8>",
-        "bit" => "Vec<u8>",
-
-        // date & time
-        "date" => "chrono::NaiveDate",
-        "daterange" => "(std::collections::Bound<chrono::NaiveDate>, std::collections::Bound<chrono::NaiveDate>)",
-        "datetime" => "chrono::NaiveDateTime",
-        "time" => "chrono::NaiveTime",
-        "timestamp" => "chrono::NaiveDateTime",
-        "tsrange" => "(std::collections::Bound<chrono::NaiveDateTime>, std::collections::Bound<chrono::NaiveDateTime>)",
-        "timestamptz" => "chrono::DateTime<chrono::Utc>",
-        "timestamptzsqlite" => "chrono::DateTime<chrono::Utc>",
-        "tstzrange" => "(std::collections::Bound<chrono::DateTime<chrono::Utc>>, std::collections::Bound<chrono::DateTime<chrono::Utc>>)",
-
-        // json
-        "json" => "serde::Value",
-        "jsonb" => "serde_json::Value",
-
-        // misc
-        "uuid" => "uuid::Uuid",
-        "interval" => "PgInterval",
-        "oid" => "u32",
-        "money" => "PgMoney",
-        "macaddr" => "[u8; 6]",
-        // "inet" => "either ipnetwork::IpNetwork or ipnet::IpNet (TODO)",
-        // "cidr" => "either ipnetwork::IpNetwork or ipnet::IpNet (TODO)",
-
-        /*
-            // panic if no type is found (this means generation is broken for this particular schema)
-            _ => panic!("Unknown type found '{schema_type}', please report this!")
-         */
-        _ => {
-            let schema_path = &config.schema_path;
-            // return the schema type if no type is found (this means generation is broken for this particular schema)
-            let _type = format!("{schema_path}sql_types::{schema_type}");
-            return Ok(_type);
+///
+/// Returns [None] if the type is not known
+fn schema_type_to_rust_type(
+    schema_type: String,
+    config: &GenerationConfig,
+) -> Result<Option<String>> {
+    if let Some(ty) = datetime_type_to_rust_type(&schema_type, config.datetime_backend) {
+        return Ok(Some(ty));
+    }
+
+    if let Some(ty) = range_type_to_rust_type(&schema_type, config)? {
+        return Ok(Some(ty));
+    }
+
+    let ip_network = config.ip_network_backend.rust_type();
+
+    Ok(Some(
+        match schema_type.to_lowercase().as_str() {
+            // boolean
+            "bool" => "bool",
+
+            // numbers
+            "tinyint" => "i8",
+            "smallint" => "i16",
+            "smallserial" => "i16",
+            "int2" => "i16",
+            "int4" => "i32",
+            "integer" => "i32",
+            "serial" => "i32",
+            "bigint" => "i64",
+            "bigserial" => "i64",
+            "int8" => "i64",
+            "float" => "f32",
+            "float4" => "f32",
+            "double" => "f64",
+            "float8" => "f64",
+            "numeric" => "bigdecimal::BigDecimal",
+            "decimal" => "bigdecimal::BigDecimal",
+
+            // string
+            "text" => "String",
+            "varchar" => "String",
+            "bpchar" => "String",
+            "char" => "String",
+            "tinytext" => "String",
+            "mediumtext" => "String",
+            "longtext" => "String",
+
+            // bytes
+            "binary" => "Vec<u8>",
+            "bytea" => "Vec<u8>",
+            "tinyblob" => "Vec<u8>",
+            "blob" => "Vec<u8>",
+            "mediumblob" => "Vec<u8>",
+            "longblob" => "Vec<u8>",
+            "varbinary" => "Vec<u8>",
+            "bit" => "Vec<u8>",
+
+            // date & time are handled in "datetime_type_to_rust_type"
+            // ranges are handled in "range_type_to_rust_type"
+
+            // json
+            "json" => "serde_json::Value",
+            "jsonb" => "serde_json::Value",
+
+            // misc
+            "uuid" => "uuid::Uuid",
+            "interval" => "PgInterval",
+            "oid" => "u32",
+            "money" => "PgMoney",
+            "macaddr" => "[u8; 6]",
+            "macaddr8" => "[u8; 8]",
+            "inet" => ip_network,
+            "cidr" => ip_network,
+
+            /*
+               // panic if no type is found (this means generation is broken for this particular schema)
+               _ => panic!("Unknown type found '{schema_type}', please report this!")
+            */
+            // unknown types are handled by the caller
+            _ => return Ok(None),
         }
-    }.to_string())
+        .to_string(),
+    ))
+}
+
+/// A function to translate diesel postgres range types into rust types
+/// the element type is resolved like any other type, so sql type overrides and the date and time backend also apply to ranges
+///
+/// Returns [None] if the type is not a range type
+fn range_type_to_rust_type(schema_type: &str, config: &GenerationConfig) -> Result<Option<String>> {
+    let Some(element) = range_element_type(schema_type) else {
+        return Ok(None);
+    };
+
+    // the element type can be overridden like any other sql type
+    let ty = match config.sql_type_override(element) {
+        Some(ty) => ty.to_string(),
+        None => {
+            let Some(ty) = schema_type_to_rust_type(element.to_string(), config)? else {
+                return Ok(None);
+            };
+
+            ty
+        }
+    };
+
+    Ok(Some(if config.range_type {
+        format!("{}common::Range<{ty}>", config.model_path)
+    } else {
+        format!("(std::ops::Bound<{ty}>, std::ops::Bound<{ty}>)")
+    }))
+}
+
+/// Get the diesel element type of a postgres range type, like `Int4` for `Int4range`
+///
+/// Returns [None] if the type is not a range type
+fn range_element_type(schema_type: &str) -> Option<&'static str> {
+    Some(match schema_type.to_lowercase().as_str() {
+        "int4range" => "Int4",
+        "int8range" => "Int8",
+        "numrange" => "Numeric",
+        "daterange" => "Date",
+        "tsrange" => "Timestamp",
+        "tstzrange" => "Timestamptz",
+        _ => return None,
+    })
+}
+
+/// A function to translate diesel date and time types into rust types of the given backend
+/// all types are switched together, so that the mapping is consistent
+///
+/// Returns [None] if the type is not a date or time type
+fn datetime_type_to_rust_type(schema_type: &str, backend: DateTimeBackend) -> Option<String> {
+    let (date, time, datetime, datetime_tz) = match backend {
+        DateTimeBackend::Chrono => (
+            "chrono::NaiveDate",
+            "chrono::NaiveTime",
+            "chrono::NaiveDateTime",
+            "chrono::DateTime<chrono::Utc>",
+        ),
+        DateTimeBackend::Time => (
+            "time::Date",
+            "time::Time",
+            "time::PrimitiveDateTime",
+            "time::OffsetDateTime",
+        ),
+    };
+    Some(match schema_type.to_lowercase().as_str() {
+        "date" => date.to_string(),
+        "datetime" => datetime.to_string(),
+        "time" => time.to_string(),
+        "timestamp" => datetime.to_string(),
+        "timestamptz" => datetime_tz.to_string(),
+        "timestamptzsqlite" => datetime_tz.to_string(),
+        _ => return None,
+    })
 }
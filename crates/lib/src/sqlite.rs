@@ -0,0 +1,225 @@
+use std::path::Path;
+
+use crate::parser::ParsedSchema;
+use crate::{GenerationConfig, Result};
+
+/// Header every sqlite 3 database file starts with
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+
+/// Get whether the file at `path` is a sqlite database, by checking the file header
+pub fn is_sqlite_database(path: &Path) -> bool {
+    use std::io::Read;
+
+    let mut header = [0u8; SQLITE_HEADER.len()];
+
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok_and(|_| header == SQLITE_HEADER)
+}
+
+/// Read all tables from a sqlite database instead of a diesel schema file
+#[cfg(not(feature = "sqlite"))]
+pub fn parse_and_generate_code(path: &Path, _config: &GenerationConfig) -> Result<ParsedSchema> {
+    Err(crate::Error::unsupported_schema_format(format!(
+        "Input \"{}\" is a sqlite database, which requires feature \"sqlite\"",
+        path.display()
+    )))
+}
+
+/// Read all tables from a sqlite database instead of a diesel schema file
+/// Tables are read from `sqlite_master`, columns from `pragma table_info` and joins from `pragma foreign_key_list`
+#[cfg(feature = "sqlite")]
+pub fn parse_and_generate_code(path: &Path, config: &GenerationConfig) -> Result<ParsedSchema> {
+    use crate::parser::{self, ParsedJoinMacro};
+
+    let connection =
+        rusqlite::Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+    let mut tables = vec![];
+    let mut joins = vec![];
+    let mut warnings = vec![];
+
+    // same tables as "diesel print-schema" would output, sorted by name
+    let table_names = connection
+        .prepare(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name != '__diesel_schema_migrations' ORDER BY name",
+        )?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+
+    for table_name in table_names {
+        let table_ident = parser::to_rust_ident(&table_name);
+
+        if config.table(&table_ident.to_string()).get_ignore() {
+            continue;
+        }
+
+        let mut columns = vec![];
+        // (position in the primary key, column name)
+        let mut primary_keys: Vec<(i64, syn::Ident)> = vec![];
+
+        let table_info = connection
+            .prepare("SELECT name, type, \"notnull\", pk FROM pragma_table_info(?1) ORDER BY cid")?
+            .query_map([&table_name], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, bool>(2)?,
+                    row.get::<_, i64>(3)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<(String, String, bool, i64)>>>()?;
+
+        let is_single_primary_key = table_info.iter().filter(|(.., pk)| *pk > 0).count() == 1;
+
+        for (column_name, declared_type, not_null, pk) in table_info {
+            let name = parser::to_rust_ident(&column_name);
+            let sql_type = parser::ParsedColumnType::Base(syn::Ident::new(
+                sqlite_type_to_diesel_type(&declared_type).as_str(),
+                proc_macro2::Span::call_site(),
+            ));
+
+            // primary keys are treated as "NOT NULL", even if not declared as such
+            let sql_type = if !not_null && pk == 0 {
+                parser::ParsedColumnType::Nullable(Box::new(sql_type))
+            } else {
+                sql_type
+            };
+
+            let mut column = parser::ParsedColumnMacro::new(
+                Some(&table_ident),
+                name.clone(),
+                column_name,
+                sql_type,
+                config,
+                &[],
+                None,
+            )?;
+
+            // a single "INTEGER PRIMARY KEY" is a alias for the rowid and is set by sqlite if not given
+            if is_single_primary_key && pk > 0 && declared_type.eq_ignore_ascii_case("integer") {
+                column.autogenerated = Some(parser::AutogeneratedReason::RowidAlias);
+            }
+
+            if pk > 0 {
+                primary_keys.push((pk, name));
+            }
+
+            columns.push(column);
+        }
+
+        primary_keys.sort_by_key(|(position, _)| *position);
+        let mut primary_keys: Vec<syn::Ident> =
+            primary_keys.into_iter().map(|(_, name)| name).collect();
+
+        // same default as the diesel "table!" macro
+        if primary_keys.is_empty() {
+            primary_keys.push(syn::Ident::new("id", proc_macro2::Span::call_site()));
+        }
+
+        // (id, seq, referenced table, column)
+        let foreign_keys = connection
+            .prepare("SELECT id, seq, \"table\", \"from\" FROM pragma_foreign_key_list(?1) ORDER BY id, seq")?
+            .query_map([&table_name], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<(i64, i64, String, String)>>>()?;
+
+        for (id, _, foreign_table, column) in foreign_keys.iter() {
+            // "joinable!" only supports single column foreign keys
+            if foreign_keys.iter().filter(|fk| fk.0 == *id).count() != 1 {
+                continue;
+            }
+
+            let foreign_table = parser::to_rust_ident(foreign_table);
+
+            // "joinable!" (and so "belongs_to") only supports one foreign key per table pair
+            if joins.iter().any(|(_, join): &(_, ParsedJoinMacro)| {
+                join.table1 == table_ident && join.table2 == foreign_table
+            }) {
+                continue;
+            }
+
+            joins.push((
+                None,
+                ParsedJoinMacro {
+                    table1: table_ident.clone(),
+                    table2: foreign_table,
+                    table1_columns: parser::to_rust_ident(column).to_string(),
+                },
+            ));
+        }
+
+        tables.push(parser::finish_table(
+            table_ident,
+            columns,
+            primary_keys,
+            config,
+            &[],
+            None,
+            &mut warnings,
+        ));
+    }
+
+    parser::generate_schema(tables, vec![], joins, warnings, config)
+}
+
+/// Map a declared sqlite column type to the diesel sql type name, using the same rules as "diesel print-schema"
+/// Types not covered by the rules (like `JSON`) are used as-is (in PascalCase), so they are resolved like any other type
+#[cfg(feature = "sqlite")]
+fn sqlite_type_to_diesel_type(declared_type: &str) -> String {
+    use heck::ToPascalCase;
+
+    let type_name = declared_type.to_lowercase();
+    // remove arguments like in "VARCHAR(255)"
+    let type_name = type_name.split('(').next().unwrap_or_default().trim();
+
+    let is_bool = type_name == "boolean"
+        || type_name == "bool"
+        || (type_name.contains("tiny") && type_name.contains("int"));
+    let is_smallint = type_name == "int2"
+        || type_name == "smallint"
+        || (type_name.contains("small") && type_name.contains("int"));
+    let is_bigint = type_name == "int8"
+        || type_name.contains("bigint")
+        || (type_name.contains("big") && type_name.contains("int"));
+
+    let diesel_type = if is_bool {
+        "Bool"
+    } else if is_smallint {
+        "SmallInt"
+    } else if is_bigint {
+        "BigInt"
+    } else if type_name.contains("int") {
+        "Integer"
+    } else if ["text", "char", "clob"]
+        .iter()
+        .any(|v| type_name.contains(v))
+    {
+        "Text"
+    } else if type_name.contains("blob") || type_name.is_empty() {
+        "Binary"
+    } else if type_name.contains("float") || type_name.contains("real") {
+        "Float"
+    } else if ["double", "num", "dec"]
+        .iter()
+        .any(|v| type_name.contains(v))
+    {
+        "Double"
+    } else if type_name == "datetime" || type_name == "timestamp" {
+        "Timestamp"
+    } else if type_name == "date" {
+        "Date"
+    } else if type_name == "time" {
+        "Time"
+    } else {
+        return crate::parser::to_rust_ident(&type_name.to_pascal_case()).to_string();
+    };
+
+    diesel_type.to_string()
+}
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+/// Non-fatal problems found during generation, the generated code may not be what is expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// A column has a sql type which is not known, so it falls back to the diesel schema `sql_types` path
+    UnknownType {
+        table: String,
+        column: String,
+        sql_type: String,
+        /// The rust type that is used as a fallback
+        fallback: String,
+    },
+    /// A `diesel::joinable!` references a table which is unknown or ignored, so the relation is not generated
+    DanglingJoinable {
+        /// Table with the foreign key
+        table: String,
+        /// Table referenced to
+        foreign_table: String,
+    },
+    /// A column given as autogenerated does not exist
+    /// `table` is [None] if the column was set in the default table options and is not found in any table
+    MissingAutogeneratedColumn {
+        table: Option<String>,
+        column: String,
+    },
+    /// A primary key column does not exist in the table columns, so it is not used as a primary key
+    MissingPrimaryKeyColumn { table: String, column: String },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::UnknownType {
+                table,
+                column,
+                sql_type,
+                fallback,
+            } => write!(
+                f,
+                "UnknownType: Column \"{table}.{column}\" has unknown sql type \"{sql_type}\", using \"{fallback}\""
+            ),
+            Warning::DanglingJoinable {
+                table,
+                foreign_table,
+            } => write!(
+                f,
+                "DanglingJoinable: Join from \"{table}\" to \"{foreign_table}\" references an unknown or ignored table"
+            ),
+            Warning::MissingAutogeneratedColumn {
+                table: Some(table),
+                column,
+            } => write!(
+                f,
+                "MissingAutogeneratedColumn: Autogenerated column \"{column}\" does not exist in table \"{table}\""
+            ),
+            Warning::MissingAutogeneratedColumn {
+                table: None,
+                column,
+            } => write!(
+                f,
+                "MissingAutogeneratedColumn: Autogenerated column \"{column}\" does not exist in any table"
+            ),
+            Warning::MissingPrimaryKeyColumn { table, column } => write!(
+                f,
+                "MissingPrimaryKeyColumn: Primary key column \"{column}\" does not exist in table \"{table}\""
+            ),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        tags -> Array<Text>,
+        nullable_tags -> Array<Nullable<Text>>,
+        optional_tags -> Nullable<Array<Text>>,
+        scores -> Array<Int4>,
+        created_at -> Timestamptz,
+    }
+}
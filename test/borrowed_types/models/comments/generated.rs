@@ -0,0 +1,177 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `comments`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=comments, primary_key(id))]
+pub struct Comments {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `body`
+    pub body: String,
+    /// Field Representing column `author`
+    pub author: Option<String>,
+    /// Field Representing column `attachment`
+    pub attachment: Vec<u8>,
+    /// Field Representing column `tags`
+    pub tags: Vec<String>,
+}
+
+/// Create struct for [`Comments`] on table `comments`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=comments)]
+pub struct CreateComments<'a> {
+    /// Field Representing column `body`
+    pub body: &'a str,
+    /// Field Representing column `author`
+    #[serde(borrow)]
+    pub author: Option<&'a str>,
+    /// Field Representing column `attachment`
+    pub attachment: &'a [u8],
+    /// Field Representing column `tags`
+    #[serde(borrow)]
+    pub tags: std::borrow::Cow<'a, [String]>,
+}
+
+/// Update struct for [`Comments`] on table `comments`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=comments)]
+pub struct UpdateComments<'a> {
+    /// Field Representing column `body`
+    #[serde(borrow)]
+    pub body: Option<&'a str>,
+    /// Field Representing column `author`
+    #[serde(borrow)]
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub author: Option<Option<&'a str>>,
+    /// Field Representing column `attachment`
+    #[serde(borrow)]
+    pub attachment: Option<&'a [u8]>,
+    /// Field Representing column `tags`
+    #[serde(borrow)]
+    pub tags: Option<std::borrow::Cow<'a, [String]>>,
+}
+
+/// Column to order [`Comments`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommentsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `body`
+    Body(OrderDirection),
+    /// Order by column `author`
+    Author(OrderDirection),
+    /// Order by column `attachment`
+    Attachment(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Comments {
+
+    /// Insert a new row on comments with a given [`CreateComments`]
+    pub fn create(db: &mut Connection, item: &CreateComments) -> QueryResult<Self> {
+        use crate::schema::comments::dsl::*;
+
+        insert_into(comments).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::comments::dsl::*;
+
+        comments.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::comments::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = comments.count().get_result(db)?;
+        let items = comments.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[CommentsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::comments::table.count().get_result(db)?;
+
+        let mut query = crate::schema::comments::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                CommentsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::id.asc()),
+                CommentsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::id.desc()),
+                CommentsOrderBy::Body(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::body.asc()),
+                CommentsOrderBy::Body(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::body.desc()),
+                CommentsOrderBy::Author(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::author.asc()),
+                CommentsOrderBy::Author(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::author.desc()),
+                CommentsOrderBy::Attachment(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::attachment.asc()),
+                CommentsOrderBy::Attachment(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::attachment.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::comments::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateComments`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateComments) -> QueryResult<Self> {
+        use crate::schema::comments::dsl::*;
+
+        diesel::update(comments.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::comments::dsl::*;
+
+        diesel::delete(comments.filter(id.eq(param_id))).execute(db)
+    }
+
+}
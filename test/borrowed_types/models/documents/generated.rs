@@ -0,0 +1,210 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `documents`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=documents, primary_key(id))]
+pub struct Documents {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `title`
+    pub title: String,
+    /// Field Representing column `summary`
+    pub summary: Option<String>,
+    /// Field Representing column `content`
+    pub content: Vec<u8>,
+    /// Field Representing column `thumbnail`
+    pub thumbnail: Option<Vec<u8>>,
+    /// Field Representing column `tags`
+    pub tags: Vec<String>,
+    /// Field Representing column `scores`
+    pub scores: Vec<Option<i32>>,
+    /// Field Representing column `revision`
+    pub revision: i32,
+}
+
+/// Create struct for [`Documents`] on table `documents`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=documents)]
+pub struct CreateDocuments<'a> {
+    /// Field Representing column `title`
+    #[serde(borrow)]
+    pub title: std::borrow::Cow<'a, str>,
+    /// Field Representing column `summary`
+    #[serde(borrow)]
+    pub summary: Option<std::borrow::Cow<'a, str>>,
+    /// Field Representing column `content`
+    #[serde(borrow)]
+    pub content: std::borrow::Cow<'a, [u8]>,
+    /// Field Representing column `thumbnail`
+    #[serde(borrow)]
+    pub thumbnail: Option<std::borrow::Cow<'a, [u8]>>,
+    /// Field Representing column `tags`
+    #[serde(borrow)]
+    pub tags: std::borrow::Cow<'a, [String]>,
+    /// Field Representing column `scores`
+    #[serde(borrow)]
+    pub scores: std::borrow::Cow<'a, [Option<i32>]>,
+    /// Field Representing column `revision`
+    pub revision: i32,
+}
+
+/// Update struct for [`Documents`] on table `documents`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=documents)]
+pub struct UpdateDocuments<'a> {
+    /// Field Representing column `title`
+    #[serde(borrow)]
+    pub title: Option<std::borrow::Cow<'a, str>>,
+    /// Field Representing column `summary`
+    #[serde(borrow)]
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub summary: Option<Option<std::borrow::Cow<'a, str>>>,
+    /// Field Representing column `content`
+    #[serde(borrow)]
+    pub content: Option<std::borrow::Cow<'a, [u8]>>,
+    /// Field Representing column `thumbnail`
+    #[serde(borrow)]
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub thumbnail: Option<Option<std::borrow::Cow<'a, [u8]>>>,
+    /// Field Representing column `tags`
+    #[serde(borrow)]
+    pub tags: Option<std::borrow::Cow<'a, [String]>>,
+    /// Field Representing column `scores`
+    #[serde(borrow)]
+    pub scores: Option<std::borrow::Cow<'a, [Option<i32>]>>,
+    /// Field Representing column `revision`
+    pub revision: Option<i32>,
+}
+
+/// Column to order [`Documents`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `title`
+    Title(OrderDirection),
+    /// Order by column `summary`
+    Summary(OrderDirection),
+    /// Order by column `content`
+    Content(OrderDirection),
+    /// Order by column `thumbnail`
+    Thumbnail(OrderDirection),
+    /// Order by column `revision`
+    Revision(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Documents {
+
+    /// Insert a new row on documents with a given [`CreateDocuments`]
+    pub fn create(db: &mut Connection, item: &CreateDocuments) -> QueryResult<Self> {
+        use crate::schema::documents::dsl::*;
+
+        insert_into(documents).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::documents::dsl::*;
+
+        documents.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::documents::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = documents.count().get_result(db)?;
+        let items = documents.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[DocumentsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::documents::table.count().get_result(db)?;
+
+        let mut query = crate::schema::documents::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                DocumentsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::documents::id.asc()),
+                DocumentsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::documents::id.desc()),
+                DocumentsOrderBy::Title(OrderDirection::Asc) => query.then_order_by(crate::schema::documents::title.asc()),
+                DocumentsOrderBy::Title(OrderDirection::Desc) => query.then_order_by(crate::schema::documents::title.desc()),
+                DocumentsOrderBy::Summary(OrderDirection::Asc) => query.then_order_by(crate::schema::documents::summary.asc()),
+                DocumentsOrderBy::Summary(OrderDirection::Desc) => query.then_order_by(crate::schema::documents::summary.desc()),
+                DocumentsOrderBy::Content(OrderDirection::Asc) => query.then_order_by(crate::schema::documents::content.asc()),
+                DocumentsOrderBy::Content(OrderDirection::Desc) => query.then_order_by(crate::schema::documents::content.desc()),
+                DocumentsOrderBy::Thumbnail(OrderDirection::Asc) => query.then_order_by(crate::schema::documents::thumbnail.asc()),
+                DocumentsOrderBy::Thumbnail(OrderDirection::Desc) => query.then_order_by(crate::schema::documents::thumbnail.desc()),
+                DocumentsOrderBy::Revision(OrderDirection::Asc) => query.then_order_by(crate::schema::documents::revision.asc()),
+                DocumentsOrderBy::Revision(OrderDirection::Desc) => query.then_order_by(crate::schema::documents::revision.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::documents::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateDocuments`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateDocuments) -> QueryResult<Self> {
+        use crate::schema::documents::dsl::*;
+
+        diesel::update(documents.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::documents::dsl::*;
+
+        diesel::delete(documents.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,3 @@
+pub mod documents;
+pub mod notes;
+pub mod comments;
@@ -0,0 +1,169 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `notes`
+#[derive(Debug, Clone, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=notes, primary_key(id))]
+pub struct Notes {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `body`
+    pub body: String,
+    /// Field Representing column `author`
+    pub author: Option<String>,
+    /// Field Representing column `attachment`
+    pub attachment: Option<Vec<u8>>,
+    /// Field Representing column `tags`
+    pub tags: Vec<String>,
+}
+
+/// Create struct for [`Notes`] on table `notes`
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=notes)]
+pub struct CreateNotes<'a> {
+    /// Field Representing column `body`
+    pub body: &'a str,
+    /// Field Representing column `author`
+    pub author: Option<&'a str>,
+    /// Field Representing column `attachment`
+    pub attachment: Option<&'a [u8]>,
+    /// Field Representing column `tags`
+    pub tags: &'a [String],
+}
+
+/// Update struct for [`Notes`] on table `notes`
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=notes)]
+pub struct UpdateNotes<'a> {
+    /// Field Representing column `body`
+    pub body: Option<&'a str>,
+    /// Field Representing column `author`
+    pub author: Option<Option<&'a str>>,
+    /// Field Representing column `attachment`
+    pub attachment: Option<Option<&'a [u8]>>,
+    /// Field Representing column `tags`
+    pub tags: Option<&'a [String]>,
+}
+
+/// Column to order [`Notes`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotesOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `body`
+    Body(OrderDirection),
+    /// Order by column `author`
+    Author(OrderDirection),
+    /// Order by column `attachment`
+    Attachment(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, )]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Notes {
+
+    /// Insert a new row on notes with a given [`CreateNotes`]
+    pub fn create(db: &mut Connection, item: &CreateNotes) -> QueryResult<Self> {
+        use crate::schema::notes::dsl::*;
+
+        insert_into(notes).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::notes::dsl::*;
+
+        notes.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::notes::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = notes.count().get_result(db)?;
+        let items = notes.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[NotesOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::notes::table.count().get_result(db)?;
+
+        let mut query = crate::schema::notes::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                NotesOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::notes::id.asc()),
+                NotesOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::notes::id.desc()),
+                NotesOrderBy::Body(OrderDirection::Asc) => query.then_order_by(crate::schema::notes::body.asc()),
+                NotesOrderBy::Body(OrderDirection::Desc) => query.then_order_by(crate::schema::notes::body.desc()),
+                NotesOrderBy::Author(OrderDirection::Asc) => query.then_order_by(crate::schema::notes::author.asc()),
+                NotesOrderBy::Author(OrderDirection::Desc) => query.then_order_by(crate::schema::notes::author.desc()),
+                NotesOrderBy::Attachment(OrderDirection::Asc) => query.then_order_by(crate::schema::notes::attachment.asc()),
+                NotesOrderBy::Attachment(OrderDirection::Desc) => query.then_order_by(crate::schema::notes::attachment.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::notes::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateNotes`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateNotes) -> QueryResult<Self> {
+        use crate::schema::notes::dsl::*;
+
+        diesel::update(notes.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::notes::dsl::*;
+
+        diesel::delete(notes.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,32 @@
+diesel::table! {
+    documents (id) {
+        id -> Int4,
+        title -> Text,
+        summary -> Nullable<Text>,
+        content -> Bytea,
+        thumbnail -> Nullable<Bytea>,
+        tags -> Array<Text>,
+        scores -> Array<Nullable<Int4>>,
+        revision -> Int4,
+    }
+}
+
+diesel::table! {
+    notes (id) {
+        id -> Int4,
+        body -> Text,
+        author -> Nullable<Text>,
+        attachment -> Nullable<Bytea>,
+        tags -> Array<Text>,
+    }
+}
+
+diesel::table! {
+    comments (id) {
+        id -> Int4,
+        body -> Text,
+        author -> Nullable<Text>,
+        attachment -> Bytea,
+        tags -> Array<Text>,
+    }
+}
@@ -0,0 +1,119 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::models::common;
+use crate::schema::*;
+
+/// Struct representing a row for table `bookings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=bookings, primary_key(id))]
+pub struct Bookings {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `during`
+    pub during: crate::models::common::Range<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Create struct for [`Bookings`] on table `bookings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=bookings)]
+pub struct CreateBookings {
+    /// Field Representing column `during`
+    pub during: crate::models::common::Range<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Update struct for [`Bookings`] on table `bookings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=bookings)]
+pub struct UpdateBookings {
+    /// Field Representing column `during`
+    pub during: Option<crate::models::common::Range<chrono::DateTime<chrono::Utc>>>,
+}
+
+/// Column to order [`Bookings`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookingsOrderBy {
+    /// Order by column `id`
+    Id(common::OrderDirection),
+    /// Order by column `during`
+    During(common::OrderDirection),
+}
+
+impl Bookings {
+
+    /// Insert a new row on bookings with a given [`CreateBookings`]
+    pub fn create(db: &mut common::ConnectionType, item: &CreateBookings) -> QueryResult<Self> {
+        use crate::schema::bookings::dsl::*;
+
+        insert_into(bookings).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut common::ConnectionType, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::bookings::dsl::*;
+
+        bookings.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut common::ConnectionType, page: i64, page_size: i64) -> QueryResult<common::PaginationResult<Self>> {
+        use crate::schema::bookings::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = bookings.count().get_result(db)?;
+        let items = bookings.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(common::PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut common::ConnectionType, page: i64, page_size: i64, order_by: &[BookingsOrderBy]) -> QueryResult<common::PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::bookings::table.count().get_result(db)?;
+
+        let mut query = crate::schema::bookings::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                BookingsOrderBy::Id(common::OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::id.asc()),
+                BookingsOrderBy::Id(common::OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::id.desc()),
+                BookingsOrderBy::During(common::OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::during.asc()),
+                BookingsOrderBy::During(common::OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::during.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::bookings::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(common::PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateBookings`]
+    pub fn update(db: &mut common::ConnectionType, param_id: i32, item: &UpdateBookings) -> QueryResult<Self> {
+        use crate::schema::bookings::dsl::*;
+
+        diesel::update(bookings.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut common::ConnectionType, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::bookings::dsl::*;
+
+        diesel::delete(bookings.filter(id.eq(param_id))).execute(db)
+    }
+
+}
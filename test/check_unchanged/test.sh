@@ -0,0 +1,11 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+ARGS=(-i schema.rs -o models -g id -c "diesel::PgConnection" --once-connection --once-common-structs --range-type --lessen-conflicts)
+
+cargo run -- "${ARGS[@]}"
+# the output is up to date after generating, so "--check" has to succeed
+cargo run -- "${ARGS[@]}" --check
@@ -0,0 +1,12 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+# no option puts anything into "common.rs", so it must not be created
+ARGS=(-i schema.rs -o models -g id -c "diesel::PgConnection")
+
+cargo run -- "${ARGS[@]}"
+# the output is up to date after generating, so "--check" has to succeed
+cargo run -- "${ARGS[@]}" --check
@@ -0,0 +1,10 @@
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        tenant_id -> Int4,
+        name -> Text,
+        password_hash -> Text,
+        search_vector -> Text,
+        created_at -> Timestamp,
+    }
+}
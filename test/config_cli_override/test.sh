@@ -0,0 +1,8 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+# options on the cli take precedence over the config file, including "[tables.*]"
+cargo run -- --once-connection=false --no-serde=false --no-impls=false
\ No newline at end of file
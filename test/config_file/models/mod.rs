@@ -0,0 +1,3 @@
+pub mod todos;
+pub mod users;
+pub mod view_todos;
@@ -0,0 +1,152 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `users`
+#[derive(Debug, Clone, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=users, primary_key(id))]
+pub struct Users {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Create struct for [`Users`] on table `users`
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=users)]
+pub struct CreateUsers<'a> {
+    /// Field Representing column `name`
+    pub name: &'a str,
+}
+
+/// Update struct for [`Users`] on table `users`
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=users)]
+pub struct UpdateUsers {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Column to order [`Users`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsersOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, )]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Users {
+
+    /// Insert a new row on users with a given [`CreateUsers`]
+    pub fn create(db: &mut Connection, item: &CreateUsers) -> QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        insert_into(users).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        users.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::users::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = users.count().get_result(db)?;
+        let items = users.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[UsersOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::users::table.count().get_result(db)?;
+
+        let mut query = crate::schema::users::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                UsersOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::users::id.asc()),
+                UsersOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::users::id.desc()),
+                UsersOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::users::name.asc()),
+                UsersOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::users::name.desc()),
+                UsersOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::users::created_at.asc()),
+                UsersOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::users::created_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::users::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateUsers`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateUsers) -> QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        diesel::update(users.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::users::dsl::*;
+
+        diesel::delete(users.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,30 @@
+diesel::table! {
+    todos (id) {
+        id -> Int4,
+        text -> Text,
+        completed -> Bool,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    view_todos (id) {
+        id -> Int4,
+        text -> Text,
+    }
+}
+
+diesel::table! {
+    audit_log (id) {
+        id -> Int4,
+        message -> Text,
+    }
+}
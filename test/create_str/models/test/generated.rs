@@ -33,6 +33,15 @@ pub struct UpdateTest {
     pub completed: Option<bool>,
 }
 
+/// Column to order [`Test`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TestOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `completed`
+    Completed(OrderDirection),
+}
+
 /// Result of a `.paginate` function
 #[derive(Debug, Serialize)]
 pub struct PaginationResult<T> {
@@ -48,6 +57,15 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
 impl Test {
 
     /// Insert a new row on test with a given [`CreateTest`]
@@ -64,13 +82,40 @@ impl Test {
         test.filter(id.eq(param_id)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
         use crate::schema::test::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = test.count().get_result(db)?;
-        let items = test.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = test.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[TestOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::test::table.count().get_result(db)?;
+
+        let mut query = crate::schema::test::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                TestOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::test::id.asc()),
+                TestOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::test::id.desc()),
+                TestOrderBy::Completed(OrderDirection::Asc) => query.then_order_by(crate::schema::test::completed.asc()),
+                TestOrderBy::Completed(OrderDirection::Desc) => query.then_order_by(crate::schema::test::completed.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::test::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
@@ -0,0 +1,214 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "time" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `events`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=events, primary_key(id))]
+pub struct Events {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `day`
+    pub day: time::Date,
+    /// Field Representing column `starts_at`
+    pub starts_at: time::Time,
+    /// Field Representing column `local_time`
+    pub local_time: time::PrimitiveDateTime,
+    /// Field Representing column `happened_at`
+    pub happened_at: time::OffsetDateTime,
+    /// Field Representing column `cancelled_at`
+    pub cancelled_at: Option<time::OffsetDateTime>,
+    /// Field Representing column `period`
+    pub period: (std::ops::Bound<time::OffsetDateTime>, std::ops::Bound<time::OffsetDateTime>),
+    /// Field Representing column `days`
+    pub days: (std::ops::Bound<time::Date>, std::ops::Bound<time::Date>),
+    /// Field Representing column `created_at`
+    pub created_at: time::OffsetDateTime,
+}
+
+/// Create struct for [`Events`] on table `events`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=events)]
+pub struct CreateEvents {
+    /// Field Representing column `day`
+    pub day: time::Date,
+    /// Field Representing column `starts_at`
+    pub starts_at: time::Time,
+    /// Field Representing column `local_time`
+    pub local_time: time::PrimitiveDateTime,
+    /// Field Representing column `happened_at`
+    pub happened_at: time::OffsetDateTime,
+    /// Field Representing column `cancelled_at`
+    pub cancelled_at: Option<time::OffsetDateTime>,
+    /// Field Representing column `period`
+    pub period: (std::ops::Bound<time::OffsetDateTime>, std::ops::Bound<time::OffsetDateTime>),
+    /// Field Representing column `days`
+    pub days: (std::ops::Bound<time::Date>, std::ops::Bound<time::Date>),
+}
+
+/// Update struct for [`Events`] on table `events`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=events)]
+pub struct UpdateEvents {
+    /// Field Representing column `day`
+    pub day: Option<time::Date>,
+    /// Field Representing column `starts_at`
+    pub starts_at: Option<time::Time>,
+    /// Field Representing column `local_time`
+    pub local_time: Option<time::PrimitiveDateTime>,
+    /// Field Representing column `happened_at`
+    pub happened_at: Option<time::OffsetDateTime>,
+    /// Field Representing column `cancelled_at`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub cancelled_at: Option<Option<time::OffsetDateTime>>,
+    /// Field Representing column `period`
+    pub period: Option<(std::ops::Bound<time::OffsetDateTime>, std::ops::Bound<time::OffsetDateTime>)>,
+    /// Field Representing column `days`
+    pub days: Option<(std::ops::Bound<time::Date>, std::ops::Bound<time::Date>)>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<time::OffsetDateTime>,
+}
+
+/// Column to order [`Events`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `day`
+    Day(OrderDirection),
+    /// Order by column `starts_at`
+    StartsAt(OrderDirection),
+    /// Order by column `local_time`
+    LocalTime(OrderDirection),
+    /// Order by column `happened_at`
+    HappenedAt(OrderDirection),
+    /// Order by column `cancelled_at`
+    CancelledAt(OrderDirection),
+    /// Order by column `period`
+    Period(OrderDirection),
+    /// Order by column `days`
+    Days(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Events {
+
+    /// Insert a new row on events with a given [`CreateEvents`]
+    pub fn create(db: &mut Connection, item: &CreateEvents) -> QueryResult<Self> {
+        use crate::schema::events::dsl::*;
+
+        insert_into(events).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::events::dsl::*;
+
+        events.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::events::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = events.count().get_result(db)?;
+        let items = events.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[EventsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::events::table.count().get_result(db)?;
+
+        let mut query = crate::schema::events::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                EventsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::events::id.asc()),
+                EventsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::events::id.desc()),
+                EventsOrderBy::Day(OrderDirection::Asc) => query.then_order_by(crate::schema::events::day.asc()),
+                EventsOrderBy::Day(OrderDirection::Desc) => query.then_order_by(crate::schema::events::day.desc()),
+                EventsOrderBy::StartsAt(OrderDirection::Asc) => query.then_order_by(crate::schema::events::starts_at.asc()),
+                EventsOrderBy::StartsAt(OrderDirection::Desc) => query.then_order_by(crate::schema::events::starts_at.desc()),
+                EventsOrderBy::LocalTime(OrderDirection::Asc) => query.then_order_by(crate::schema::events::local_time.asc()),
+                EventsOrderBy::LocalTime(OrderDirection::Desc) => query.then_order_by(crate::schema::events::local_time.desc()),
+                EventsOrderBy::HappenedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::events::happened_at.asc()),
+                EventsOrderBy::HappenedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::events::happened_at.desc()),
+                EventsOrderBy::CancelledAt(OrderDirection::Asc) => query.then_order_by(crate::schema::events::cancelled_at.asc()),
+                EventsOrderBy::CancelledAt(OrderDirection::Desc) => query.then_order_by(crate::schema::events::cancelled_at.desc()),
+                EventsOrderBy::Period(OrderDirection::Asc) => query.then_order_by(crate::schema::events::period.asc()),
+                EventsOrderBy::Period(OrderDirection::Desc) => query.then_order_by(crate::schema::events::period.desc()),
+                EventsOrderBy::Days(OrderDirection::Asc) => query.then_order_by(crate::schema::events::days.asc()),
+                EventsOrderBy::Days(OrderDirection::Desc) => query.then_order_by(crate::schema::events::days.desc()),
+                EventsOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::events::created_at.asc()),
+                EventsOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::events::created_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::events::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateEvents`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateEvents) -> QueryResult<Self> {
+        use crate::schema::events::dsl::*;
+
+        diesel::update(events.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::events::dsl::*;
+
+        diesel::delete(events.filter(id.eq(param_id))).execute(db)
+    }
+
+}
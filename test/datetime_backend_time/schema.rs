@@ -0,0 +1,13 @@
+diesel::table! {
+    events (id) {
+        id -> Int4,
+        day -> Date,
+        starts_at -> Time,
+        local_time -> Timestamp,
+        happened_at -> Timestamptz,
+        cancelled_at -> Nullable<Timestamptz>,
+        period -> Tstzrange,
+        days -> Daterange,
+        created_at -> Timestamptz,
+    }
+}
@@ -0,0 +1,195 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `todos`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=todos, primary_key(id))]
+pub struct Todos {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `unsigned`
+    pub unsigned: u32,
+    /// Field Representing column `text`
+    pub text: String,
+    /// Field Representing column `completed`
+    pub completed: bool,
+    /// Field Representing column `type`
+    pub type_: String,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Field Representing column `updated_at`
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Create struct for [`Todos`] on table `todos`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=todos)]
+pub struct CreateTodos {
+    /// Field Representing column `unsigned`
+    pub unsigned: u32,
+    /// Field Representing column `text`
+    pub text: String,
+    /// Field Representing column `completed`
+    pub completed: bool,
+    /// Field Representing column `type`
+    pub type_: String,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Field Representing column `updated_at`
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Update struct for [`Todos`] on table `todos`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=todos)]
+pub struct UpdateTodos {
+    /// Field Representing column `unsigned`
+    pub unsigned: Option<u32>,
+    /// Field Representing column `text`
+    pub text: Option<String>,
+    /// Field Representing column `completed`
+    pub completed: Option<bool>,
+    /// Field Representing column `type`
+    pub type_: Option<String>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Field Representing column `updated_at`
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Column to order [`Todos`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TodosOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `unsigned`
+    Unsigned(OrderDirection),
+    /// Order by column `text`
+    Text(OrderDirection),
+    /// Order by column `completed`
+    Completed(OrderDirection),
+    /// Order by column `type`
+    Type(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+    /// Order by column `updated_at`
+    UpdatedAt(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Todos {
+
+    /// Insert a new row on todos with a given [`CreateTodos`]
+    pub fn create(db: &mut Connection, item: &CreateTodos) -> QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        insert_into(todos).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        todos.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::todos::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = todos.count().get_result(db)?;
+        let items = todos.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[TodosOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::todos::table.count().get_result(db)?;
+
+        let mut query = crate::schema::todos::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                TodosOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::id.asc()),
+                TodosOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::id.desc()),
+                TodosOrderBy::Unsigned(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::unsigned.asc()),
+                TodosOrderBy::Unsigned(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::unsigned.desc()),
+                TodosOrderBy::Text(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::text.asc()),
+                TodosOrderBy::Text(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::text.desc()),
+                TodosOrderBy::Completed(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::completed.asc()),
+                TodosOrderBy::Completed(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::completed.desc()),
+                TodosOrderBy::Type(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::type_.asc()),
+                TodosOrderBy::Type(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::type_.desc()),
+                TodosOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::created_at.asc()),
+                TodosOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::created_at.desc()),
+                TodosOrderBy::UpdatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::todos::updated_at.asc()),
+                TodosOrderBy::UpdatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::todos::updated_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::todos::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateTodos`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateTodos) -> QueryResult<Self> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::update(todos.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::todos::dsl::*;
+
+        diesel::delete(todos.filter(id.eq(param_id))).execute(db)
+    }
+
+}
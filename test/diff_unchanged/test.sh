@@ -0,0 +1,17 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+ARGS=(-i schema.rs -o models -g id -c "diesel::PgConnection")
+
+cargo run -- "${ARGS[@]}"
+# the output is up to date after generating, so "diff" has to print nothing
+DIFF="$(cargo run -- diff "${ARGS[@]}")" || exit 1
+
+if [ -n "$DIFF" ]; then
+    echo "Expected no diff for up to date models, got:"
+    echo "$DIFF"
+    exit 1
+fi
@@ -0,0 +1,367 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `products`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=products, primary_key(id))]
+pub struct Products {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `description`
+    pub description: Option<String>,
+    /// Field Representing column `price`
+    pub price: bigdecimal::BigDecimal,
+    /// Field Representing column `stock`
+    pub stock: Option<i64>,
+    /// Field Representing column `active`
+    pub active: bool,
+    /// Field Representing column `tags`
+    pub tags: Vec<String>,
+    /// Field Representing column `attributes`
+    pub attributes: serde_json::Value,
+    /// Field Representing column `raw_attributes`
+    pub raw_attributes: serde_json::Value,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Field Representing column `type`
+    pub type_: String,
+}
+
+/// Create struct for [`Products`] on table `products`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=products)]
+pub struct CreateProducts {
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `description`
+    pub description: Option<String>,
+    /// Field Representing column `price`
+    pub price: bigdecimal::BigDecimal,
+    /// Field Representing column `stock`
+    pub stock: Option<i64>,
+    /// Field Representing column `active`
+    pub active: bool,
+    /// Field Representing column `tags`
+    pub tags: Vec<String>,
+    /// Field Representing column `attributes`
+    pub attributes: serde_json::Value,
+    /// Field Representing column `raw_attributes`
+    pub raw_attributes: serde_json::Value,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Field Representing column `type`
+    pub type_: String,
+}
+
+/// Update struct for [`Products`] on table `products`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=products)]
+pub struct UpdateProducts {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+    /// Field Representing column `description`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub description: Option<Option<String>>,
+    /// Field Representing column `price`
+    pub price: Option<bigdecimal::BigDecimal>,
+    /// Field Representing column `stock`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub stock: Option<Option<i64>>,
+    /// Field Representing column `active`
+    pub active: Option<bool>,
+    /// Field Representing column `tags`
+    pub tags: Option<Vec<String>>,
+    /// Field Representing column `attributes`
+    pub attributes: Option<serde_json::Value>,
+    /// Field Representing column `raw_attributes`
+    pub raw_attributes: Option<serde_json::Value>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Field Representing column `type`
+    pub type_: Option<String>,
+}
+
+/// Column to order [`Products`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProductsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+    /// Order by column `description`
+    Description(OrderDirection),
+    /// Order by column `price`
+    Price(OrderDirection),
+    /// Order by column `stock`
+    Stock(OrderDirection),
+    /// Order by column `active`
+    Active(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+    /// Order by column `type`
+    Type(OrderDirection),
+}
+
+/// Filter for [`Products`] on table `products`, only rows matching all set fields are selected
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProductsFilter {
+    /// Filter column `id` to be equal to the value
+    pub id: Option<i32>,
+    /// Filter column `id` to be greater than or equal to the value
+    pub id_gte: Option<i32>,
+    /// Filter column `id` to be less than or equal to the value
+    pub id_lte: Option<i32>,
+    /// Filter column `name` to be equal to the value
+    pub name: Option<String>,
+    /// Filter column `name` to match the `LIKE` pattern
+    pub name_like: Option<String>,
+    /// Filter column `description` to be equal to the value
+    pub description: Option<String>,
+    /// Filter column `description` to be `NULL` (`true`) or not `NULL` (`false`)
+    pub description_is_null: Option<bool>,
+    /// Filter column `description` to match the `LIKE` pattern
+    pub description_like: Option<String>,
+    /// Filter column `price` to be equal to the value
+    pub price: Option<bigdecimal::BigDecimal>,
+    /// Filter column `price` to be greater than or equal to the value
+    pub price_gte: Option<bigdecimal::BigDecimal>,
+    /// Filter column `price` to be less than or equal to the value
+    pub price_lte: Option<bigdecimal::BigDecimal>,
+    /// Filter column `stock` to be equal to the value
+    pub stock: Option<i64>,
+    /// Filter column `stock` to be `NULL` (`true`) or not `NULL` (`false`)
+    pub stock_is_null: Option<bool>,
+    /// Filter column `stock` to be greater than or equal to the value
+    pub stock_gte: Option<i64>,
+    /// Filter column `stock` to be less than or equal to the value
+    pub stock_lte: Option<i64>,
+    /// Filter column `active` to be equal to the value
+    pub active: Option<bool>,
+    /// Filter column `tags` to be equal to the value
+    pub tags: Option<Vec<String>>,
+    /// Filter column `attributes` to be equal to the value
+    pub attributes: Option<serde_json::Value>,
+    /// Filter column `created_at` to be equal to the value
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filter column `created_at` to be greater than or equal to the value
+    pub created_at_gte: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filter column `created_at` to be less than or equal to the value
+    pub created_at_lte: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filter column `type` to be equal to the value
+    pub type_: Option<String>,
+    /// Filter column `type` to match the `LIKE` pattern
+    pub type_like: Option<String>,
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Products {
+
+    /// Insert a new row on products with a given [`CreateProducts`]
+    pub fn create(db: &mut Connection, item: &CreateProducts) -> QueryResult<Self> {
+        use crate::schema::products::dsl::*;
+
+        insert_into(products).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::products::dsl::*;
+
+        products.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::products::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = products.count().get_result(db)?;
+        let items = products.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[ProductsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::products::table.count().get_result(db)?;
+
+        let mut query = crate::schema::products::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                ProductsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::products::id.asc()),
+                ProductsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::products::id.desc()),
+                ProductsOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::products::name.asc()),
+                ProductsOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::products::name.desc()),
+                ProductsOrderBy::Description(OrderDirection::Asc) => query.then_order_by(crate::schema::products::description.asc()),
+                ProductsOrderBy::Description(OrderDirection::Desc) => query.then_order_by(crate::schema::products::description.desc()),
+                ProductsOrderBy::Price(OrderDirection::Asc) => query.then_order_by(crate::schema::products::price.asc()),
+                ProductsOrderBy::Price(OrderDirection::Desc) => query.then_order_by(crate::schema::products::price.desc()),
+                ProductsOrderBy::Stock(OrderDirection::Asc) => query.then_order_by(crate::schema::products::stock.asc()),
+                ProductsOrderBy::Stock(OrderDirection::Desc) => query.then_order_by(crate::schema::products::stock.desc()),
+                ProductsOrderBy::Active(OrderDirection::Asc) => query.then_order_by(crate::schema::products::active.asc()),
+                ProductsOrderBy::Active(OrderDirection::Desc) => query.then_order_by(crate::schema::products::active.desc()),
+                ProductsOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::products::created_at.asc()),
+                ProductsOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::products::created_at.desc()),
+                ProductsOrderBy::Type(OrderDirection::Asc) => query.then_order_by(crate::schema::products::type_.asc()),
+                ProductsOrderBy::Type(OrderDirection::Desc) => query.then_order_by(crate::schema::products::type_.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::products::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the rows matching all set fields of the given [`ProductsFilter`] ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn filter(db: &mut Connection, filter: &ProductsFilter, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        // a boxed query can only be used once, so it is built for both the count and the items
+        let query = || {
+            let mut query = crate::schema::products::table.into_boxed();
+            if let Some(value) = &filter.id {
+                query = query.filter(crate::schema::products::id.eq(value));
+            }
+            if let Some(value) = &filter.id_gte {
+                query = query.filter(crate::schema::products::id.ge(value));
+            }
+            if let Some(value) = &filter.id_lte {
+                query = query.filter(crate::schema::products::id.le(value));
+            }
+            if let Some(value) = &filter.name {
+                query = query.filter(crate::schema::products::name.eq(value));
+            }
+            if let Some(value) = &filter.name_like {
+                query = query.filter(crate::schema::products::name.like(value));
+            }
+            if let Some(value) = &filter.description {
+                query = query.filter(crate::schema::products::description.eq(value));
+            }
+            if let Some(is_null) = filter.description_is_null {
+                query = if is_null { query.filter(crate::schema::products::description.is_null()) } else { query.filter(crate::schema::products::description.is_not_null()) };
+            }
+            if let Some(value) = &filter.description_like {
+                query = query.filter(crate::schema::products::description.like(value));
+            }
+            if let Some(value) = &filter.price {
+                query = query.filter(crate::schema::products::price.eq(value));
+            }
+            if let Some(value) = &filter.price_gte {
+                query = query.filter(crate::schema::products::price.ge(value));
+            }
+            if let Some(value) = &filter.price_lte {
+                query = query.filter(crate::schema::products::price.le(value));
+            }
+            if let Some(value) = &filter.stock {
+                query = query.filter(crate::schema::products::stock.eq(value));
+            }
+            if let Some(is_null) = filter.stock_is_null {
+                query = if is_null { query.filter(crate::schema::products::stock.is_null()) } else { query.filter(crate::schema::products::stock.is_not_null()) };
+            }
+            if let Some(value) = &filter.stock_gte {
+                query = query.filter(crate::schema::products::stock.ge(value));
+            }
+            if let Some(value) = &filter.stock_lte {
+                query = query.filter(crate::schema::products::stock.le(value));
+            }
+            if let Some(value) = &filter.active {
+                query = query.filter(crate::schema::products::active.eq(value));
+            }
+            if let Some(value) = &filter.tags {
+                query = query.filter(crate::schema::products::tags.eq(value));
+            }
+            if let Some(value) = &filter.attributes {
+                query = query.filter(crate::schema::products::attributes.eq(value));
+            }
+            if let Some(value) = &filter.created_at {
+                query = query.filter(crate::schema::products::created_at.eq(value));
+            }
+            if let Some(value) = &filter.created_at_gte {
+                query = query.filter(crate::schema::products::created_at.ge(value));
+            }
+            if let Some(value) = &filter.created_at_lte {
+                query = query.filter(crate::schema::products::created_at.le(value));
+            }
+            if let Some(value) = &filter.type_ {
+                query = query.filter(crate::schema::products::type_.eq(value));
+            }
+            if let Some(value) = &filter.type_like {
+                query = query.filter(crate::schema::products::type_.like(value));
+            }
+            query
+        };
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = query().count().get_result(db)?;
+        let items = query().then_order_by(crate::schema::products::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateProducts`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateProducts) -> QueryResult<Self> {
+        use crate::schema::products::dsl::*;
+
+        diesel::update(products.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::products::dsl::*;
+
+        diesel::delete(products.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,16 @@
+diesel::table! {
+    products (id) {
+        id -> Int4,
+        name -> Text,
+        description -> Nullable<Varchar>,
+        price -> Numeric,
+        stock -> Nullable<Int8>,
+        active -> Bool,
+        tags -> Array<Text>,
+        attributes -> Jsonb,
+        raw_attributes -> Json,
+        created_at -> Timestamptz,
+        #[sql_name = "type"]
+        type_ -> Text,
+    }
+}
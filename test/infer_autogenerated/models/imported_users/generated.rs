@@ -0,0 +1,157 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `imported_users`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=imported_users, primary_key(id))]
+pub struct ImportedUsers {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// Create struct for [`ImportedUsers`] on table `imported_users`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=imported_users)]
+pub struct CreateImportedUsers {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// Update struct for [`ImportedUsers`] on table `imported_users`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=imported_users)]
+pub struct UpdateImportedUsers {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Column to order [`ImportedUsers`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportedUsersOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl ImportedUsers {
+
+    /// Insert a new row on imported_users with a given [`CreateImportedUsers`]
+    pub fn create(db: &mut Connection, item: &CreateImportedUsers) -> QueryResult<Self> {
+        use crate::schema::imported_users::dsl::*;
+
+        insert_into(imported_users).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::imported_users::dsl::*;
+
+        imported_users.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::imported_users::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = imported_users.count().get_result(db)?;
+        let items = imported_users.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[ImportedUsersOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::imported_users::table.count().get_result(db)?;
+
+        let mut query = crate::schema::imported_users::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                ImportedUsersOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::imported_users::id.asc()),
+                ImportedUsersOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::imported_users::id.desc()),
+                ImportedUsersOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::imported_users::name.asc()),
+                ImportedUsersOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::imported_users::name.desc()),
+                ImportedUsersOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::imported_users::created_at.asc()),
+                ImportedUsersOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::imported_users::created_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::imported_users::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateImportedUsers`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateImportedUsers) -> QueryResult<Self> {
+        use crate::schema::imported_users::dsl::*;
+
+        diesel::update(imported_users.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::imported_users::dsl::*;
+
+        diesel::delete(imported_users.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,3 @@
+pub mod posts;
+pub mod users;
+pub mod imported_users;
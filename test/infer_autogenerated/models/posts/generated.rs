@@ -0,0 +1,163 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `posts`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=posts, primary_key(id))]
+pub struct Posts {
+    /// Field Representing column `id`
+    pub id: i64,
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `title`
+    pub title: String,
+    /// Field Representing column `created_at`
+    pub created_at: chrono::NaiveDateTime,
+}
+
+/// Create struct for [`Posts`] on table `posts`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=posts)]
+pub struct CreatePosts {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `title`
+    pub title: String,
+}
+
+/// Update struct for [`Posts`] on table `posts`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=posts)]
+pub struct UpdatePosts {
+    /// Field Representing column `user_id`
+    pub user_id: Option<i32>,
+    /// Field Representing column `title`
+    pub title: Option<String>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+/// Column to order [`Posts`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PostsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `user_id`
+    UserId(OrderDirection),
+    /// Order by column `title`
+    Title(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Posts {
+
+    /// Insert a new row on posts with a given [`CreatePosts`]
+    pub fn create(db: &mut Connection, item: &CreatePosts) -> QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        insert_into(posts).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i64) -> QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        posts.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = posts.count().get_result(db)?;
+        let items = posts.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[PostsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::posts::table.count().get_result(db)?;
+
+        let mut query = crate::schema::posts::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                PostsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::id.asc()),
+                PostsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::id.desc()),
+                PostsOrderBy::UserId(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::user_id.asc()),
+                PostsOrderBy::UserId(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::user_id.desc()),
+                PostsOrderBy::Title(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::title.asc()),
+                PostsOrderBy::Title(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::title.desc()),
+                PostsOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::created_at.asc()),
+                PostsOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::created_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::posts::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdatePosts`]
+    pub fn update(db: &mut Connection, param_id: i64, item: &UpdatePosts) -> QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::update(posts.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i64) -> QueryResult<usize> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::delete(posts.filter(id.eq(param_id))).execute(db)
+    }
+
+}
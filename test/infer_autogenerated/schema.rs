@@ -0,0 +1,25 @@
+diesel::table! {
+    posts (id) {
+        id -> BigSerial,
+        user_id -> Int4,
+        title -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    users (id) {
+        id -> Serial,
+        name -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    imported_users (id) {
+        id -> Int4,
+        name -> Text,
+        created_at -> Timestamp,
+    }
+}
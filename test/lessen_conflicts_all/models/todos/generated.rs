@@ -1,4 +1,5 @@
 /* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
 
 use crate::diesel::*;
 use serde::{Deserialize, Serialize};
@@ -57,6 +58,25 @@ pub struct UpdateTodos {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Column to order [`Todos`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TodosOrderBy {
+    /// Order by column `id`
+    Id(common::OrderDirection),
+    /// Order by column `unsigned`
+    Unsigned(common::OrderDirection),
+    /// Order by column `text`
+    Text(common::OrderDirection),
+    /// Order by column `completed`
+    Completed(common::OrderDirection),
+    /// Order by column `type`
+    Type(common::OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(common::OrderDirection),
+    /// Order by column `updated_at`
+    UpdatedAt(common::OrderDirection),
+}
+
 /// Result of a `.paginate` function
 #[derive(Debug, Serialize)]
 pub struct PaginationResult<T> {
@@ -72,6 +92,15 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
 impl Todos {
 
     /// Insert a new row on todos with a given [`CreateTodos`]
@@ -88,13 +117,50 @@ impl Todos {
         todos.filter(id.eq(param_id)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut common::ConnectionType, page: i64, page_size: i64) -> QueryResult<common::PaginationResult<Self>> {
         use crate::schema::todos::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = todos.count().get_result(db)?;
-        let items = todos.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = todos.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(common::PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut common::ConnectionType, page: i64, page_size: i64, order_by: &[TodosOrderBy]) -> QueryResult<common::PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::todos::table.count().get_result(db)?;
+
+        let mut query = crate::schema::todos::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                TodosOrderBy::Id(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::id.asc()),
+                TodosOrderBy::Id(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::id.desc()),
+                TodosOrderBy::Unsigned(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::unsigned.asc()),
+                TodosOrderBy::Unsigned(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::unsigned.desc()),
+                TodosOrderBy::Text(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::text.asc()),
+                TodosOrderBy::Text(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::text.desc()),
+                TodosOrderBy::Completed(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::completed.asc()),
+                TodosOrderBy::Completed(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::completed.desc()),
+                TodosOrderBy::Type(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::type_.asc()),
+                TodosOrderBy::Type(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::type_.desc()),
+                TodosOrderBy::CreatedAt(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::created_at.asc()),
+                TodosOrderBy::CreatedAt(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::created_at.desc()),
+                TodosOrderBy::UpdatedAt(common::OrderDirection::Asc) => query.then_order_by(crate::schema::todos::updated_at.asc()),
+                TodosOrderBy::UpdatedAt(common::OrderDirection::Desc) => query.then_order_by(crate::schema::todos::updated_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::todos::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(common::PaginationResult {
             items,
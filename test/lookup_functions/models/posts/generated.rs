@@ -0,0 +1,228 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::users::Users;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `posts`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations, AsChangeset)]
+#[diesel(table_name=posts, primary_key(id), belongs_to(Users, foreign_key=user_id))]
+pub struct Posts {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `reviewer_id`
+    pub reviewer_id: Option<i32>,
+    /// Field Representing column `title`
+    pub title: String,
+}
+
+/// Create struct for [`Posts`] on table `posts`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=posts)]
+pub struct CreatePosts {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `reviewer_id`
+    pub reviewer_id: Option<i32>,
+    /// Field Representing column `title`
+    pub title: String,
+}
+
+/// Update struct for [`Posts`] on table `posts`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=posts)]
+pub struct UpdatePosts {
+    /// Field Representing column `user_id`
+    pub user_id: Option<i32>,
+    /// Field Representing column `reviewer_id`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub reviewer_id: Option<Option<i32>>,
+    /// Field Representing column `title`
+    pub title: Option<String>,
+}
+
+/// Column to order [`Posts`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PostsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `user_id`
+    UserId(OrderDirection),
+    /// Order by column `reviewer_id`
+    ReviewerId(OrderDirection),
+    /// Order by column `title`
+    Title(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Posts {
+
+    /// Insert a new row on posts with a given [`CreatePosts`]
+    pub fn create(db: &mut Connection, item: &CreatePosts) -> QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        insert_into(posts).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        posts.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = posts.count().get_result(db)?;
+        let items = posts.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[PostsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::posts::table.count().get_result(db)?;
+
+        let mut query = crate::schema::posts::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                PostsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::id.asc()),
+                PostsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::id.desc()),
+                PostsOrderBy::UserId(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::user_id.asc()),
+                PostsOrderBy::UserId(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::user_id.desc()),
+                PostsOrderBy::ReviewerId(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::reviewer_id.asc()),
+                PostsOrderBy::ReviewerId(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::reviewer_id.desc()),
+                PostsOrderBy::Title(OrderDirection::Asc) => query.then_order_by(crate::schema::posts::title.asc()),
+                PostsOrderBy::Title(OrderDirection::Desc) => query.then_order_by(crate::schema::posts::title.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::posts::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `user_id`
+    pub fn filter_by_user_id(db: &mut Connection, param_user_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        posts.filter(user_id.eq(&param_user_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `user_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_user_id(db: &mut Connection, param_user_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = posts.filter(user_id.eq(&param_user_id)).count().get_result(db)?;
+        let items = posts.filter(user_id.eq(&param_user_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `reviewer_id`
+    pub fn filter_by_reviewer_id(db: &mut Connection, param_reviewer_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        posts.filter(reviewer_id.eq(&param_reviewer_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `reviewer_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_reviewer_id(db: &mut Connection, param_reviewer_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::posts::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = posts.filter(reviewer_id.eq(&param_reviewer_id)).count().get_result(db)?;
+        let items = posts.filter(reviewer_id.eq(&param_reviewer_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdatePosts`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdatePosts) -> QueryResult<Self> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::update(posts.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::posts::dsl::*;
+
+        diesel::delete(posts.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Users`]
+    pub fn for_user(db: &mut Connection, parent: &Users) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Users`] this row belongs to with column `user_id`
+    pub fn user(&self, db: &mut Connection) -> QueryResult<Users> {
+        let fk = &self.user_id;
+
+        crate::schema::users::table.find(fk).select(Users::as_select()).first::<Users>(db)
+    }
+
+}
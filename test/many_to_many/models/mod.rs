@@ -0,0 +1,3 @@
+pub mod users;
+pub mod roles;
+pub mod user_roles;
@@ -0,0 +1,183 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::user_roles::UserRoles;
+use crate::models::users::Users;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `roles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, AsChangeset)]
+#[diesel(table_name=roles, primary_key(id))]
+pub struct Roles {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `title`
+    pub title: String,
+}
+
+/// Create struct for [`Roles`] on table `roles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=roles)]
+pub struct CreateRoles {
+    /// Field Representing column `title`
+    pub title: String,
+}
+
+/// Update struct for [`Roles`] on table `roles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=roles)]
+pub struct UpdateRoles {
+    /// Field Representing column `title`
+    pub title: Option<String>,
+}
+
+/// Column to order [`Roles`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RolesOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `title`
+    Title(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Roles {
+
+    /// Insert a new row on roles with a given [`CreateRoles`]
+    pub fn create(db: &mut Connection, item: &CreateRoles) -> QueryResult<Self> {
+        use crate::schema::roles::dsl::*;
+
+        insert_into(roles).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::roles::dsl::*;
+
+        roles.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::roles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = roles.count().get_result(db)?;
+        let items = roles.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[RolesOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::roles::table.count().get_result(db)?;
+
+        let mut query = crate::schema::roles::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                RolesOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::roles::id.asc()),
+                RolesOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::roles::id.desc()),
+                RolesOrderBy::Title(OrderDirection::Asc) => query.then_order_by(crate::schema::roles::title.asc()),
+                RolesOrderBy::Title(OrderDirection::Desc) => query.then_order_by(crate::schema::roles::title.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::roles::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateRoles`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateRoles) -> QueryResult<Self> {
+        use crate::schema::roles::dsl::*;
+
+        diesel::update(roles.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::roles::dsl::*;
+
+        diesel::delete(roles.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Load all rows of table `user_roles` which belong to the given rows, grouped by the given rows
+    pub fn load_user_roles(db: &mut Connection, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<UserRoles>)>> {
+        let children = UserRoles::belonging_to(parents).select(UserRoles::as_select()).load::<UserRoles>(db)?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }
+
+    /// Get all [`Users`]s linked to this row through table `user_roles`
+    pub fn users(&self, db: &mut Connection) -> QueryResult<Vec<Users>> {
+        crate::schema::users::table.inner_join(crate::schema::user_roles::table).filter(crate::schema::user_roles::role_id.eq(&self.id)).select(Users::as_select()).load::<Users>(db)
+    }
+
+    /// Link the row of table `users` with the given `user_id` to this row, by inserting into table `user_roles`
+    pub fn link_user(&self, db: &mut Connection, user_id: i32) -> QueryResult<usize> {
+        diesel::insert_into(crate::schema::user_roles::table).values((crate::schema::user_roles::role_id.eq(&self.id), crate::schema::user_roles::user_id.eq(user_id))).execute(db)
+    }
+
+    /// Unlink the row of table `users` with the given `user_id` from this row, by deleting from table `user_roles`
+    pub fn unlink_user(&self, db: &mut Connection, user_id: i32) -> QueryResult<usize> {
+        diesel::delete(crate::schema::user_roles::table.filter(crate::schema::user_roles::role_id.eq(&self.id)).filter(crate::schema::user_roles::user_id.eq(user_id))).execute(db)
+    }
+
+    /// Replace all links of this row in table `user_roles` with links to the rows of table `users` with the given ids, in a transaction
+    pub fn set_users(&self, db: &mut Connection, ids: &[i32]) -> QueryResult<()> {
+        diesel::Connection::transaction(db, |db| {
+            diesel::delete(crate::schema::user_roles::table.filter(crate::schema::user_roles::role_id.eq(&self.id))).execute(db)?;
+
+            if !ids.is_empty() {
+                let rows = ids.iter().map(|id| (crate::schema::user_roles::role_id.eq(&self.id), crate::schema::user_roles::user_id.eq(id))).collect::<Vec<_>>();
+                diesel::insert_into(crate::schema::user_roles::table).values(rows).execute(db)?;
+            }
+
+            Ok::<(), diesel::result::Error>(())
+        })
+    }
+
+}
@@ -0,0 +1,207 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::users::Users;
+use crate::models::roles::Roles;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `user_roles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations)]
+#[diesel(table_name=user_roles, primary_key(user_id,role_id), belongs_to(Users, foreign_key=user_id) , belongs_to(Roles, foreign_key=role_id))]
+pub struct UserRoles {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `role_id`
+    pub role_id: i32,
+}
+
+/// Create struct for [`UserRoles`] on table `user_roles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable)]
+#[diesel(table_name=user_roles)]
+pub struct CreateUserRoles {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `role_id`
+    pub role_id: i32,
+}
+
+/// Column to order [`UserRoles`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UserRolesOrderBy {
+    /// Order by column `user_id`
+    UserId(OrderDirection),
+    /// Order by column `role_id`
+    RoleId(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl UserRoles {
+
+    /// Insert a new row on user_roles with a given [`CreateUserRoles`]
+    pub fn create(db: &mut Connection, item: &CreateUserRoles) -> QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        insert_into(user_roles).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_user_id: i32, param_role_id: i32) -> QueryResult<Self> {
+        use crate::schema::user_roles::dsl::*;
+
+        user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::user_roles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = user_roles.count().get_result(db)?;
+        let items = user_roles.order_by((user_id, role_id)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[UserRolesOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::user_roles::table.count().get_result(db)?;
+
+        let mut query = crate::schema::user_roles::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                UserRolesOrderBy::UserId(OrderDirection::Asc) => query.then_order_by(crate::schema::user_roles::user_id.asc()),
+                UserRolesOrderBy::UserId(OrderDirection::Desc) => query.then_order_by(crate::schema::user_roles::user_id.desc()),
+                UserRolesOrderBy::RoleId(OrderDirection::Asc) => query.then_order_by(crate::schema::user_roles::role_id.asc()),
+                UserRolesOrderBy::RoleId(OrderDirection::Desc) => query.then_order_by(crate::schema::user_roles::role_id.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::user_roles::user_id.asc()).then_order_by(crate::schema::user_roles::role_id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `user_id`
+    pub fn filter_by_user_id(db: &mut Connection, param_user_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::user_roles::dsl::*;
+
+        user_roles.filter(user_id.eq(&param_user_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `user_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_user_id(db: &mut Connection, param_user_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::user_roles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = user_roles.filter(user_id.eq(&param_user_id)).count().get_result(db)?;
+        let items = user_roles.filter(user_id.eq(&param_user_id)).order_by((user_id, role_id)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `role_id`
+    pub fn filter_by_role_id(db: &mut Connection, param_role_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::user_roles::dsl::*;
+
+        user_roles.filter(role_id.eq(&param_role_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `role_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_role_id(db: &mut Connection, param_role_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::user_roles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = user_roles.filter(role_id.eq(&param_role_id)).count().get_result(db)?;
+        let items = user_roles.filter(role_id.eq(&param_role_id)).order_by((user_id, role_id)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_user_id: i32, param_role_id: i32) -> QueryResult<usize> {
+        use crate::schema::user_roles::dsl::*;
+
+        diesel::delete(user_roles.filter(user_id.eq(param_user_id)).filter(role_id.eq(param_role_id))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Users`]
+    pub fn for_user(db: &mut Connection, parent: &Users) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Users`] this row belongs to with column `user_id`
+    pub fn user(&self, db: &mut Connection) -> QueryResult<Users> {
+        let fk = &self.user_id;
+
+        crate::schema::users::table.find(fk).select(Users::as_select()).first::<Users>(db)
+    }
+
+    /// Get all rows which belong to the given [`Roles`]
+    pub fn for_role(db: &mut Connection, parent: &Roles) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Roles`] this row belongs to with column `role_id`
+    pub fn role(&self, db: &mut Connection) -> QueryResult<Roles> {
+        let fk = &self.role_id;
+
+        crate::schema::roles::table.find(fk).select(Roles::as_select()).first::<Roles>(db)
+    }
+
+}
@@ -0,0 +1,24 @@
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    roles (id) {
+        id -> Int4,
+        title -> Text,
+    }
+}
+
+diesel::table! {
+    user_roles (user_id, role_id) {
+        user_id -> Int4,
+        role_id -> Int4,
+    }
+}
+
+diesel::joinable!(user_roles -> users (user_id));
+diesel::joinable!(user_roles -> roles (role_id));
+diesel::allow_tables_to_appear_in_same_query!(users, roles, user_roles);
@@ -0,0 +1,186 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::users::Users;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `logs`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations, AsChangeset)]
+#[diesel(table_name=logs, primary_key(), belongs_to(Users, foreign_key=user_id))]
+pub struct Logs {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `message`
+    pub message: String,
+}
+
+/// Create struct for [`Logs`] on table `logs`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=logs)]
+pub struct CreateLogs {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `message`
+    pub message: String,
+}
+
+/// Update struct for [`Logs`] on table `logs`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=logs)]
+pub struct UpdateLogs {
+    /// Field Representing column `user_id`
+    pub user_id: Option<i32>,
+    /// Field Representing column `message`
+    pub message: Option<String>,
+}
+
+/// Column to order [`Logs`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogsOrderBy {
+    /// Order by column `user_id`
+    UserId(OrderDirection),
+    /// Order by column `message`
+    Message(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Logs {
+
+    /// Insert a new row on logs with a given [`CreateLogs`]
+    pub fn create(db: &mut Connection, item: &CreateLogs) -> QueryResult<Self> {
+        use crate::schema::logs::dsl::*;
+
+        insert_into(logs).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, ) -> QueryResult<Self> {
+        use crate::schema::logs::dsl::*;
+
+        logs..first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::logs::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = logs.count().get_result(db)?;
+        let items = logs.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[LogsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::logs::table.count().get_result(db)?;
+
+        let mut query = crate::schema::logs::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                LogsOrderBy::UserId(OrderDirection::Asc) => query.then_order_by(crate::schema::logs::user_id.asc()),
+                LogsOrderBy::UserId(OrderDirection::Desc) => query.then_order_by(crate::schema::logs::user_id.desc()),
+                LogsOrderBy::Message(OrderDirection::Asc) => query.then_order_by(crate::schema::logs::message.asc()),
+                LogsOrderBy::Message(OrderDirection::Desc) => query.then_order_by(crate::schema::logs::message.desc()),
+            };
+        }
+        let items = query.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `user_id`
+    pub fn filter_by_user_id(db: &mut Connection, param_user_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::logs::dsl::*;
+
+        logs.filter(user_id.eq(&param_user_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `user_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_user_id(db: &mut Connection, param_user_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::logs::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = logs.filter(user_id.eq(&param_user_id)).count().get_result(db)?;
+        let items = logs.filter(user_id.eq(&param_user_id)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateLogs`]
+    pub fn update(db: &mut Connection, , item: &UpdateLogs) -> QueryResult<Self> {
+        use crate::schema::logs::dsl::*;
+
+        diesel::update(logs.).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, ) -> QueryResult<usize> {
+        use crate::schema::logs::dsl::*;
+
+        diesel::delete(logs.).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Users`]
+    pub fn for_user(db: &mut Connection, parent: &Users) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Users`] this row belongs to with column `user_id`
+    pub fn user(&self, db: &mut Connection) -> QueryResult<Users> {
+        let fk = &self.user_id;
+
+        crate::schema::users::table.find(fk).select(Users::as_select()).first::<Users>(db)
+    }
+
+}
@@ -0,0 +1,15 @@
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    logs (log_id) {
+        user_id -> Int4,
+        message -> Text,
+    }
+}
+
+diesel::joinable!(logs -> users (user_id));
@@ -0,0 +1,8 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+# the primary key of "logs" is not a column, so its rows are paginated without a order
+cargo run -- -i schema.rs -o models -c "diesel::PgConnection"
\ No newline at end of file
@@ -39,6 +39,17 @@ pub struct UpdateUsers {
     pub secret: Option<String>,
 }
 
+/// Column to order [`Users`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UsersOrderBy {
+    /// Order by column `name`
+    Name(OrderDirection),
+    /// Order by column `address`
+    Address(OrderDirection),
+    /// Order by column `secret`
+    Secret(OrderDirection),
+}
+
 /// Result of a `.paginate` function
 #[derive(Debug, Serialize)]
 pub struct PaginationResult<T> {
@@ -54,6 +65,15 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
 impl Users {
 
     /// Insert a new row on users with a given [`CreateUsers`]
@@ -70,13 +90,42 @@ impl Users {
         users.filter(name.eq(param_name)).filter(address.eq(param_address)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
         use crate::schema::users::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = users.count().get_result(db)?;
-        let items = users.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = users.order_by((name, address)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[UsersOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::users::table.count().get_result(db)?;
+
+        let mut query = crate::schema::users::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                UsersOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::users::name.asc()),
+                UsersOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::users::name.desc()),
+                UsersOrderBy::Address(OrderDirection::Asc) => query.then_order_by(crate::schema::users::address.asc()),
+                UsersOrderBy::Address(OrderDirection::Desc) => query.then_order_by(crate::schema::users::address.desc()),
+                UsersOrderBy::Secret(OrderDirection::Asc) => query.then_order_by(crate::schema::users::secret.asc()),
+                UsersOrderBy::Secret(OrderDirection::Desc) => query.then_order_by(crate::schema::users::secret.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::users::name.asc()).then_order_by(crate::schema::users::address.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
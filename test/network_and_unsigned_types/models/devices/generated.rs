@@ -0,0 +1,207 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `devices`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=devices, primary_key(id))]
+pub struct Devices {
+    /// Field Representing column `id`
+    pub id: u64,
+    /// Field Representing column `ip`
+    pub ip: ipnet::IpNet,
+    /// Field Representing column `subnet`
+    pub subnet: Option<ipnet::IpNet>,
+    /// Field Representing column `mac`
+    pub mac: [u8; 8],
+    /// Field Representing column `legacy_mac`
+    pub legacy_mac: [u8; 6],
+    /// Field Representing column `port`
+    pub port: Option<u16>,
+    /// Field Representing column `seen_count`
+    pub seen_count: Option<u32>,
+    /// Field Representing column `flags`
+    pub flags: u8,
+}
+
+/// Create struct for [`Devices`] on table `devices`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=devices)]
+pub struct CreateDevices {
+    /// Field Representing column `ip`
+    pub ip: ipnet::IpNet,
+    /// Field Representing column `subnet`
+    pub subnet: Option<ipnet::IpNet>,
+    /// Field Representing column `mac`
+    pub mac: [u8; 8],
+    /// Field Representing column `legacy_mac`
+    pub legacy_mac: [u8; 6],
+    /// Field Representing column `port`
+    pub port: Option<u16>,
+    /// Field Representing column `seen_count`
+    pub seen_count: Option<u32>,
+    /// Field Representing column `flags`
+    pub flags: u8,
+}
+
+/// Update struct for [`Devices`] on table `devices`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=devices)]
+pub struct UpdateDevices {
+    /// Field Representing column `ip`
+    pub ip: Option<ipnet::IpNet>,
+    /// Field Representing column `subnet`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub subnet: Option<Option<ipnet::IpNet>>,
+    /// Field Representing column `mac`
+    pub mac: Option<[u8; 8]>,
+    /// Field Representing column `legacy_mac`
+    pub legacy_mac: Option<[u8; 6]>,
+    /// Field Representing column `port`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub port: Option<Option<u16>>,
+    /// Field Representing column `seen_count`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub seen_count: Option<Option<u32>>,
+    /// Field Representing column `flags`
+    pub flags: Option<u8>,
+}
+
+/// Column to order [`Devices`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DevicesOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `ip`
+    Ip(OrderDirection),
+    /// Order by column `subnet`
+    Subnet(OrderDirection),
+    /// Order by column `mac`
+    Mac(OrderDirection),
+    /// Order by column `legacy_mac`
+    LegacyMac(OrderDirection),
+    /// Order by column `port`
+    Port(OrderDirection),
+    /// Order by column `seen_count`
+    SeenCount(OrderDirection),
+    /// Order by column `flags`
+    Flags(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Devices {
+
+    /// Insert a new row on devices with a given [`CreateDevices`]
+    pub fn create(db: &mut Connection, item: &CreateDevices) -> QueryResult<Self> {
+        use crate::schema::devices::dsl::*;
+
+        insert_into(devices).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: u64) -> QueryResult<Self> {
+        use crate::schema::devices::dsl::*;
+
+        devices.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::devices::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = devices.count().get_result(db)?;
+        let items = devices.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[DevicesOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::devices::table.count().get_result(db)?;
+
+        let mut query = crate::schema::devices::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                DevicesOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::id.asc()),
+                DevicesOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::id.desc()),
+                DevicesOrderBy::Ip(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::ip.asc()),
+                DevicesOrderBy::Ip(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::ip.desc()),
+                DevicesOrderBy::Subnet(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::subnet.asc()),
+                DevicesOrderBy::Subnet(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::subnet.desc()),
+                DevicesOrderBy::Mac(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::mac.asc()),
+                DevicesOrderBy::Mac(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::mac.desc()),
+                DevicesOrderBy::LegacyMac(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::legacy_mac.asc()),
+                DevicesOrderBy::LegacyMac(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::legacy_mac.desc()),
+                DevicesOrderBy::Port(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::port.asc()),
+                DevicesOrderBy::Port(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::port.desc()),
+                DevicesOrderBy::SeenCount(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::seen_count.asc()),
+                DevicesOrderBy::SeenCount(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::seen_count.desc()),
+                DevicesOrderBy::Flags(OrderDirection::Asc) => query.then_order_by(crate::schema::devices::flags.asc()),
+                DevicesOrderBy::Flags(OrderDirection::Desc) => query.then_order_by(crate::schema::devices::flags.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::devices::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateDevices`]
+    pub fn update(db: &mut Connection, param_id: u64, item: &UpdateDevices) -> QueryResult<Self> {
+        use crate::schema::devices::dsl::*;
+
+        diesel::update(devices.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: u64) -> QueryResult<usize> {
+        use crate::schema::devices::dsl::*;
+
+        diesel::delete(devices.filter(id.eq(param_id))).execute(db)
+    }
+
+}
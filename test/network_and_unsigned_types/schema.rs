@@ -0,0 +1,12 @@
+diesel::table! {
+    devices (id) {
+        id -> Unsigned<BigInt>,
+        ip -> Inet,
+        subnet -> Nullable<Cidr>,
+        mac -> MacAddr8,
+        legacy_mac -> MacAddr,
+        port -> Unsigned<Nullable<SmallInt>>,
+        seen_count -> Nullable<Unsigned<Integer>>,
+        flags -> Unsigned<TinyInt>,
+    }
+}
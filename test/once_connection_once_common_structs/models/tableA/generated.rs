@@ -1,12 +1,13 @@
 /* This file is generated and managed by dsync */
 
 use crate::diesel::*;
+use crate::models::table_b::TableB;
 use serde::{Deserialize, Serialize};
 use crate::models::common::*;
 use crate::schema::*;
 
 /// Struct representing a row for table `tableA`
-#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable)]
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable)]
 #[diesel(table_name=tableA, primary_key(_id))]
 pub struct TableA {
     /// Field Representing column `_id`
@@ -21,6 +22,13 @@ pub struct CreateTableA {
     pub _id: i32,
 }
 
+/// Column to order [`TableA`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableAOrderBy {
+    /// Order by column `_id`
+    Id(OrderDirection),
+}
+
 impl TableA {
 
     /// Insert a new row on tableA with a given [`CreateTableA`]
@@ -37,13 +45,38 @@ impl TableA {
         tableA.filter(_id.eq(param__id)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
         use crate::schema::tableA::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = tableA.count().get_result(db)?;
-        let items = tableA.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = tableA.order_by(_id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[TableAOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::tableA::table.count().get_result(db)?;
+
+        let mut query = crate::schema::tableA::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                TableAOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::tableA::_id.asc()),
+                TableAOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::tableA::_id.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::tableA::_id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
@@ -62,4 +95,12 @@ impl TableA {
         diesel::delete(tableA.filter(_id.eq(param__id))).execute(db)
     }
 
+    /// Load all rows of table `tableB` which belong to the given rows, grouped by the given rows
+    pub fn load_table_b(db: &mut Connection, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<TableB>)>> {
+        let children = TableB::belonging_to(parents).select(TableB::as_select()).load::<TableB>(db)?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }
+
 }
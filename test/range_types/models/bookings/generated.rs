@@ -0,0 +1,178 @@
+/* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `bookings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=bookings, primary_key(id))]
+pub struct Bookings {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `seats`
+    pub seats: crate::models::common::Range<i32>,
+    /// Field Representing column `price`
+    pub price: Option<crate::models::common::Range<bigdecimal::BigDecimal>>,
+    /// Field Representing column `period`
+    pub period: crate::models::common::Range<chrono::DateTime<chrono::Utc>>,
+    /// Field Representing column `days`
+    pub days: crate::models::common::Range<chrono::NaiveDate>,
+}
+
+/// Create struct for [`Bookings`] on table `bookings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=bookings)]
+pub struct CreateBookings {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `seats`
+    pub seats: crate::models::common::Range<i32>,
+    /// Field Representing column `price`
+    pub price: Option<crate::models::common::Range<bigdecimal::BigDecimal>>,
+    /// Field Representing column `period`
+    pub period: crate::models::common::Range<chrono::DateTime<chrono::Utc>>,
+    /// Field Representing column `days`
+    pub days: crate::models::common::Range<chrono::NaiveDate>,
+}
+
+/// Update struct for [`Bookings`] on table `bookings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=bookings)]
+pub struct UpdateBookings {
+    /// Field Representing column `seats`
+    pub seats: Option<crate::models::common::Range<i32>>,
+    /// Field Representing column `price`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub price: Option<Option<crate::models::common::Range<bigdecimal::BigDecimal>>>,
+    /// Field Representing column `period`
+    pub period: Option<crate::models::common::Range<chrono::DateTime<chrono::Utc>>>,
+    /// Field Representing column `days`
+    pub days: Option<crate::models::common::Range<chrono::NaiveDate>>,
+}
+
+/// Column to order [`Bookings`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BookingsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `seats`
+    Seats(OrderDirection),
+    /// Order by column `price`
+    Price(OrderDirection),
+    /// Order by column `period`
+    Period(OrderDirection),
+    /// Order by column `days`
+    Days(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Bookings {
+
+    /// Insert a new row on bookings with a given [`CreateBookings`]
+    pub fn create(db: &mut Connection, item: &CreateBookings) -> QueryResult<Self> {
+        use crate::schema::bookings::dsl::*;
+
+        insert_into(bookings).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::bookings::dsl::*;
+
+        bookings.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::bookings::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = bookings.count().get_result(db)?;
+        let items = bookings.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[BookingsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::bookings::table.count().get_result(db)?;
+
+        let mut query = crate::schema::bookings::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                BookingsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::id.asc()),
+                BookingsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::id.desc()),
+                BookingsOrderBy::Seats(OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::seats.asc()),
+                BookingsOrderBy::Seats(OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::seats.desc()),
+                BookingsOrderBy::Price(OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::price.asc()),
+                BookingsOrderBy::Price(OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::price.desc()),
+                BookingsOrderBy::Period(OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::period.asc()),
+                BookingsOrderBy::Period(OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::period.desc()),
+                BookingsOrderBy::Days(OrderDirection::Asc) => query.then_order_by(crate::schema::bookings::days.asc()),
+                BookingsOrderBy::Days(OrderDirection::Desc) => query.then_order_by(crate::schema::bookings::days.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::bookings::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateBookings`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateBookings) -> QueryResult<Self> {
+        use crate::schema::bookings::dsl::*;
+
+        diesel::update(bookings.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::bookings::dsl::*;
+
+        diesel::delete(bookings.filter(id.eq(param_id))).execute(db)
+    }
+
+}
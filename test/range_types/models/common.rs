@@ -0,0 +1,87 @@
+/* This file is generated and managed by dsync */
+/// A postgres range, with [None] as a unbounded side
+/// Converts to and from the diesel representation `(Bound<T>, Bound<T>)`
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = diesel::sql_types::Int4range)]
+#[diesel(sql_type = diesel::sql_types::Int8range)]
+#[diesel(sql_type = diesel::sql_types::Numrange)]
+#[diesel(sql_type = diesel::sql_types::Daterange)]
+#[diesel(sql_type = diesel::sql_types::Tsrange)]
+#[diesel(sql_type = diesel::sql_types::Tstzrange)]
+pub struct Range<T> {
+    /// Start of the range, [None] if unbounded
+    pub start: Option<T>,
+    /// End of the range, [None] if unbounded
+    pub end: Option<T>,
+    /// Whether `start` is part of the range
+    pub start_inclusive: bool,
+    /// Whether `end` is part of the range
+    pub end_inclusive: bool,
+}
+
+impl<T> From<(std::ops::Bound<T>, std::ops::Bound<T>)> for Range<T> {
+    fn from((start, end): (std::ops::Bound<T>, std::ops::Bound<T>)) -> Self {
+        use std::ops::Bound;
+
+        let (start, start_inclusive) = match start {
+            Bound::Included(value) => (Some(value), true),
+            Bound::Excluded(value) => (Some(value), false),
+            Bound::Unbounded => (None, false),
+        };
+        let (end, end_inclusive) = match end {
+            Bound::Included(value) => (Some(value), true),
+            Bound::Excluded(value) => (Some(value), false),
+            Bound::Unbounded => (None, false),
+        };
+
+        Self {
+            start,
+            end,
+            start_inclusive,
+            end_inclusive,
+        }
+    }
+}
+
+impl<T> From<Range<T>> for (std::ops::Bound<T>, std::ops::Bound<T>) {
+    fn from(range: Range<T>) -> Self {
+        use std::ops::Bound;
+
+        let start = match range.start {
+            Some(value) if range.start_inclusive => Bound::Included(value),
+            Some(value) => Bound::Excluded(value),
+            None => Bound::Unbounded,
+        };
+        let end = match range.end {
+            Some(value) if range.end_inclusive => Bound::Included(value),
+            Some(value) => Bound::Excluded(value),
+            None => Bound::Unbounded,
+        };
+
+        (start, end)
+    }
+}
+
+impl<T, ST> diesel::deserialize::FromSql<diesel::sql_types::Range<ST>, diesel::pg::Pg> for Range<T>
+where
+    (std::ops::Bound<T>, std::ops::Bound<T>): diesel::deserialize::FromSql<diesel::sql_types::Range<ST>, diesel::pg::Pg>,
+{
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        let bounds: (std::ops::Bound<T>, std::ops::Bound<T>) =
+            diesel::deserialize::FromSql::<diesel::sql_types::Range<ST>, diesel::pg::Pg>::from_sql(bytes)?;
+
+        Ok(bounds.into())
+    }
+}
+
+impl<T, ST> diesel::serialize::ToSql<diesel::sql_types::Range<ST>, diesel::pg::Pg> for Range<T>
+where
+    T: Clone + std::fmt::Debug,
+    (std::ops::Bound<T>, std::ops::Bound<T>): diesel::serialize::ToSql<diesel::sql_types::Range<ST>, diesel::pg::Pg>,
+{
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        let bounds: (std::ops::Bound<T>, std::ops::Bound<T>) = self.clone().into();
+
+        diesel::serialize::ToSql::<diesel::sql_types::Range<ST>, diesel::pg::Pg>::to_sql(&bounds, &mut out.reborrow())
+    }
+}
@@ -0,0 +1,9 @@
+diesel::table! {
+    bookings (id) {
+        id -> Int4,
+        seats -> Int4range,
+        price -> Nullable<Numrange>,
+        period -> Tstzrange,
+        days -> Daterange,
+    }
+}
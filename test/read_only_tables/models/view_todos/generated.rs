@@ -17,6 +17,15 @@ pub struct ViewTodos {
     pub text: String,
 }
 
+/// Column to order [`ViewTodos`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViewTodosOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `text`
+    Text(OrderDirection),
+}
+
 /// Result of a `.paginate` function
 #[derive(Debug, Serialize)]
 pub struct PaginationResult<T> {
@@ -32,6 +41,15 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
 impl ViewTodos {
 
     /// Get a specific row with the primary key
@@ -41,13 +59,40 @@ impl ViewTodos {
         view_todos.filter(id.eq(param_id)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
         use crate::schema::view_todos::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = view_todos.count().get_result(db)?;
-        let items = view_todos.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = view_todos.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[ViewTodosOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::view_todos::table.count().get_result(db)?;
+
+        let mut query = crate::schema::view_todos::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                ViewTodosOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::view_todos::id.asc()),
+                ViewTodosOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::view_todos::id.desc()),
+                ViewTodosOrderBy::Text(OrderDirection::Asc) => query.then_order_by(crate::schema::view_todos::text.asc()),
+                ViewTodosOrderBy::Text(OrderDirection::Desc) => query.then_order_by(crate::schema::view_todos::text.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::view_todos::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
@@ -13,3 +13,12 @@ pub struct PaginationResult<T> {
     /// Number of pages in total
     pub num_pages: i64,
 }
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
@@ -0,0 +1,129 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `points`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name=points, primary_key(id))]
+pub struct Points {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `pair`
+    pub pair: (i32, String),
+    /// Field Representing column `labelled`
+    pub labelled: Option<(Option<i32>, Vec<Option<String>>)>,
+    /// Field Representing column `single`
+    pub single: (i64,),
+}
+
+/// Create struct for [`Points`] on table `points`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable)]
+#[diesel(table_name=points)]
+pub struct CreatePoints {
+    /// Field Representing column `id`
+    pub id: i32,
+}
+
+/// Column to order [`Points`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PointsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Points {
+
+    /// Insert a new row on points with a given [`CreatePoints`]
+    pub fn create(db: &mut Connection, item: &CreatePoints) -> QueryResult<Self> {
+        use crate::schema::points::dsl::*;
+
+        insert_into(points).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::points::dsl::*;
+
+        points.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::points::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = points.count().get_result(db)?;
+        let items = points.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[PointsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::points::table.count().get_result(db)?;
+
+        let mut query = crate::schema::points::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                PointsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::points::id.asc()),
+                PointsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::points::id.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::points::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::points::dsl::*;
+
+        diesel::delete(points.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,8 @@
+diesel::table! {
+    points (id) {
+        id -> Int4,
+        pair -> Record<(Integer, Text)>,
+        labelled -> Nullable<Record<(Nullable<Int4>, Array<Nullable<Text>>)>>,
+        single -> Record<(Int8,)>,
+    }
+}
@@ -0,0 +1,132 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::posts::Posts;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `editors`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable)]
+#[diesel(table_name=editors, primary_key(code))]
+pub struct Editors {
+    /// Field Representing column `code`
+    pub code: i32,
+}
+
+/// Create struct for [`Editors`] on table `editors`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable)]
+#[diesel(table_name=editors)]
+pub struct CreateEditors {
+    /// Field Representing column `code`
+    pub code: i32,
+}
+
+/// Column to order [`Editors`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EditorsOrderBy {
+    /// Order by column `code`
+    Code(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Editors {
+
+    /// Insert a new row on editors with a given [`CreateEditors`]
+    pub fn create(db: &mut Connection, item: &CreateEditors) -> QueryResult<Self> {
+        use crate::schema::editors::dsl::*;
+
+        insert_into(editors).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_code: i32) -> QueryResult<Self> {
+        use crate::schema::editors::dsl::*;
+
+        editors.filter(code.eq(param_code)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::editors::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = editors.count().get_result(db)?;
+        let items = editors.order_by(code).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[EditorsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::editors::table.count().get_result(db)?;
+
+        let mut query = crate::schema::editors::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                EditorsOrderBy::Code(OrderDirection::Asc) => query.then_order_by(crate::schema::editors::code.asc()),
+                EditorsOrderBy::Code(OrderDirection::Desc) => query.then_order_by(crate::schema::editors::code.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::editors::code.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_code: i32) -> QueryResult<usize> {
+        use crate::schema::editors::dsl::*;
+
+        diesel::delete(editors.filter(code.eq(param_code))).execute(db)
+    }
+
+    /// Load all rows of table `posts` which belong to the given rows, grouped by the given rows
+    pub fn load_posts(db: &mut Connection, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<Posts>)>> {
+        let children = Posts::belonging_to(parents).select(Posts::as_select()).load::<Posts>(db)?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }
+
+}
@@ -0,0 +1,3 @@
+pub mod users;
+pub mod posts;
+pub mod editors;
@@ -0,0 +1,25 @@
+diesel::table! {
+    users (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    posts (id) {
+        id -> Int4,
+        user_id -> Int4,
+        editor_id -> Nullable<Int4>,
+        title -> Text,
+    }
+}
+
+diesel::table! {
+    editors (code) {
+        code -> Int4,
+    }
+}
+
+diesel::joinable!(posts -> users (user_id));
+diesel::joinable!(posts -> editors (editor_id));
+diesel::allow_tables_to_appear_in_same_query!(users, posts, editors);
@@ -0,0 +1,3 @@
+pub mod types;
+pub mod users;
+pub mod sessions;
@@ -0,0 +1,182 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::auth::users::Users;
+use serde::{Deserialize, Serialize};
+use crate::schema::auth::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `sessions`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations, AsChangeset)]
+#[diesel(table_name=sessions, primary_key(id), belongs_to(Users, foreign_key=user_id))]
+pub struct Sessions {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+}
+
+/// Create struct for [`Sessions`] on table `sessions`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=sessions)]
+pub struct CreateSessions {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+}
+
+/// Update struct for [`Sessions`] on table `sessions`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=sessions)]
+pub struct UpdateSessions {
+    /// Field Representing column `user_id`
+    pub user_id: Option<i32>,
+}
+
+/// Column to order [`Sessions`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `user_id`
+    UserId(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Sessions {
+
+    /// Insert a new row on sessions with a given [`CreateSessions`]
+    pub fn create(db: &mut Connection, item: &CreateSessions) -> QueryResult<Self> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        insert_into(sessions).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        sessions.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = sessions.count().get_result(db)?;
+        let items = sessions.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[SessionsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::auth::sessions::table.count().get_result(db)?;
+
+        let mut query = crate::schema::auth::sessions::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                SessionsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::auth::sessions::id.asc()),
+                SessionsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::auth::sessions::id.desc()),
+                SessionsOrderBy::UserId(OrderDirection::Asc) => query.then_order_by(crate::schema::auth::sessions::user_id.asc()),
+                SessionsOrderBy::UserId(OrderDirection::Desc) => query.then_order_by(crate::schema::auth::sessions::user_id.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::auth::sessions::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `user_id`
+    pub fn filter_by_user_id(db: &mut Connection, param_user_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        sessions.filter(user_id.eq(&param_user_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `user_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_user_id(db: &mut Connection, param_user_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = sessions.filter(user_id.eq(&param_user_id)).count().get_result(db)?;
+        let items = sessions.filter(user_id.eq(&param_user_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateSessions`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateSessions) -> QueryResult<Self> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        diesel::update(sessions.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::auth::sessions::dsl::*;
+
+        diesel::delete(sessions.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Users`]
+    pub fn for_user(db: &mut Connection, parent: &Users) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Users`] this row belongs to with column `user_id`
+    pub fn user(&self, db: &mut Connection) -> QueryResult<Users> {
+        let fk = &self.user_id;
+
+        crate::schema::auth::users::table.find(fk).select(Users::as_select()).first::<Users>(db)
+    }
+
+}
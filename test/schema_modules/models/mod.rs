@@ -0,0 +1,2 @@
+pub mod todos;
+pub mod auth;
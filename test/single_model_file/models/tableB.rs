@@ -36,6 +36,15 @@ pub struct UpdateTableB {
     pub link: Option<i32>,
 }
 
+/// Column to order [`TableB`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableBOrderBy {
+    /// Order by column `_id`
+    Id(OrderDirection),
+    /// Order by column `link`
+    Link(OrderDirection),
+}
+
 /// Result of a `.paginate` function
 #[derive(Debug, Serialize)]
 pub struct PaginationResult<T> {
@@ -51,6 +60,15 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
 impl TableB {
 
     /// Insert a new row on tableB with a given [`CreateTableB`]
@@ -67,13 +85,65 @@ impl TableB {
         tableB.filter(_id.eq(param__id)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
         use crate::schema::tableB::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = tableB.count().get_result(db)?;
-        let items = tableB.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = tableB.order_by(_id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[TableBOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::tableB::table.count().get_result(db)?;
+
+        let mut query = crate::schema::tableB::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                TableBOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::tableB::_id.asc()),
+                TableBOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::tableB::_id.desc()),
+                TableBOrderBy::Link(OrderDirection::Asc) => query.then_order_by(crate::schema::tableB::link.asc()),
+                TableBOrderBy::Link(OrderDirection::Desc) => query.then_order_by(crate::schema::tableB::link.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::tableB::_id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `link`
+    pub fn filter_by_link(db: &mut Connection, param_link: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::tableB::dsl::*;
+
+        tableB.filter(link.eq(&param_link)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `link` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_link(db: &mut Connection, param_link: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::tableB::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = tableB.filter(link.eq(&param_link)).count().get_result(db)?;
+        let items = tableB.filter(link.eq(&param_link)).order_by(_id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
@@ -99,4 +169,16 @@ impl TableB {
         diesel::delete(tableB.filter(_id.eq(param__id))).execute(db)
     }
 
+    /// Get all rows which belong to the given [`TableA`]
+    pub fn for_link(db: &mut Connection, parent: &TableA) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`TableA`] this row belongs to with column `link`
+    pub fn link(&self, db: &mut Connection) -> QueryResult<TableA> {
+        let fk = &self.link;
+
+        crate::schema::tableA::table.find(fk).select(TableA::as_select()).first::<TableA>(db)
+    }
+
 }
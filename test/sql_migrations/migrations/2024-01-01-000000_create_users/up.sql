@@ -0,0 +1,13 @@
+CREATE TABLE users (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    email TEXT,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    legacy_score INTEGER,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+SELECT diesel_manage_updated_at('users');
+
+COMMENT ON COLUMN users.name IS 'Name shown to other users';
@@ -0,0 +1 @@
+DROP TABLE post_tags; DROP TABLE posts; DROP TABLE drafts;
@@ -0,0 +1,18 @@
+CREATE TABLE posts (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    body TEXT,
+    published BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE post_tags (
+    post_id BIGINT NOT NULL,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (post_id, tag),
+    CONSTRAINT post_tags_post_id_fkey FOREIGN KEY (post_id) REFERENCES posts (id)
+);
+
+CREATE TABLE drafts (
+    id SERIAL PRIMARY KEY
+);
@@ -0,0 +1,4 @@
+ALTER TABLE articles RENAME TO posts;
+ALTER TABLE users RENAME COLUMN biography TO bio;
+ALTER TABLE users ALTER COLUMN email DROP NOT NULL;
+ALTER TABLE users DROP COLUMN bio, ADD COLUMN legacy_score INTEGER;
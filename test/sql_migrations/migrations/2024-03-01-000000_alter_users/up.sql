@@ -0,0 +1,5 @@
+ALTER TABLE users ADD COLUMN bio TEXT, DROP COLUMN legacy_score;
+ALTER TABLE users ALTER COLUMN email SET NOT NULL;
+ALTER TABLE users RENAME COLUMN bio TO biography;
+ALTER TABLE posts RENAME TO articles;
+DROP TABLE drafts;
@@ -0,0 +1,220 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::users::Users;
+use crate::models::post_tags::PostTags;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `articles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations, AsChangeset)]
+#[diesel(table_name=articles, primary_key(id), belongs_to(Users, foreign_key=user_id))]
+pub struct Articles {
+    /// Field Representing column `id`
+    pub id: i64,
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `title`
+    pub title: String,
+    /// Field Representing column `body`
+    pub body: Option<String>,
+    /// Field Representing column `published`
+    pub published: bool,
+}
+
+/// Create struct for [`Articles`] on table `articles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=articles)]
+pub struct CreateArticles {
+    /// Field Representing column `user_id`
+    pub user_id: i32,
+    /// Field Representing column `title`
+    pub title: String,
+    /// Field Representing column `body`
+    pub body: Option<String>,
+}
+
+/// Update struct for [`Articles`] on table `articles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=articles)]
+pub struct UpdateArticles {
+    /// Field Representing column `user_id`
+    pub user_id: Option<i32>,
+    /// Field Representing column `title`
+    pub title: Option<String>,
+    /// Field Representing column `body`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub body: Option<Option<String>>,
+    /// Field Representing column `published`
+    pub published: Option<bool>,
+}
+
+/// Column to order [`Articles`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArticlesOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `user_id`
+    UserId(OrderDirection),
+    /// Order by column `title`
+    Title(OrderDirection),
+    /// Order by column `body`
+    Body(OrderDirection),
+    /// Order by column `published`
+    Published(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Articles {
+
+    /// Insert a new row on articles with a given [`CreateArticles`]
+    pub fn create(db: &mut Connection, item: &CreateArticles) -> QueryResult<Self> {
+        use crate::schema::articles::dsl::*;
+
+        insert_into(articles).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i64) -> QueryResult<Self> {
+        use crate::schema::articles::dsl::*;
+
+        articles.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::articles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = articles.count().get_result(db)?;
+        let items = articles.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[ArticlesOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::articles::table.count().get_result(db)?;
+
+        let mut query = crate::schema::articles::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                ArticlesOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::articles::id.asc()),
+                ArticlesOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::articles::id.desc()),
+                ArticlesOrderBy::UserId(OrderDirection::Asc) => query.then_order_by(crate::schema::articles::user_id.asc()),
+                ArticlesOrderBy::UserId(OrderDirection::Desc) => query.then_order_by(crate::schema::articles::user_id.desc()),
+                ArticlesOrderBy::Title(OrderDirection::Asc) => query.then_order_by(crate::schema::articles::title.asc()),
+                ArticlesOrderBy::Title(OrderDirection::Desc) => query.then_order_by(crate::schema::articles::title.desc()),
+                ArticlesOrderBy::Body(OrderDirection::Asc) => query.then_order_by(crate::schema::articles::body.asc()),
+                ArticlesOrderBy::Body(OrderDirection::Desc) => query.then_order_by(crate::schema::articles::body.desc()),
+                ArticlesOrderBy::Published(OrderDirection::Asc) => query.then_order_by(crate::schema::articles::published.asc()),
+                ArticlesOrderBy::Published(OrderDirection::Desc) => query.then_order_by(crate::schema::articles::published.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::articles::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `user_id`
+    pub fn filter_by_user_id(db: &mut Connection, param_user_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::articles::dsl::*;
+
+        articles.filter(user_id.eq(&param_user_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `user_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_user_id(db: &mut Connection, param_user_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::articles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = articles.filter(user_id.eq(&param_user_id)).count().get_result(db)?;
+        let items = articles.filter(user_id.eq(&param_user_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateArticles`]
+    pub fn update(db: &mut Connection, param_id: i64, item: &UpdateArticles) -> QueryResult<Self> {
+        use crate::schema::articles::dsl::*;
+
+        diesel::update(articles.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i64) -> QueryResult<usize> {
+        use crate::schema::articles::dsl::*;
+
+        diesel::delete(articles.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Users`]
+    pub fn for_user(db: &mut Connection, parent: &Users) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Users`] this row belongs to with column `user_id`
+    pub fn user(&self, db: &mut Connection) -> QueryResult<Users> {
+        let fk = &self.user_id;
+
+        crate::schema::users::table.find(fk).select(Users::as_select()).first::<Users>(db)
+    }
+
+    /// Load all rows of table `post_tags` which belong to the given rows, grouped by the given rows
+    pub fn load_post_tags(db: &mut Connection, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<PostTags>)>> {
+        let children = PostTags::belonging_to(parents).select(PostTags::as_select()).load::<PostTags>(db)?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }
+
+}
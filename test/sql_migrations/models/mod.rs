@@ -0,0 +1,3 @@
+pub mod users;
+pub mod articles;
+pub mod post_tags;
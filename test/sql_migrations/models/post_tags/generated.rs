@@ -0,0 +1,169 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::articles::Articles;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `post_tags`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations)]
+#[diesel(table_name=post_tags, primary_key(post_id,tag), belongs_to(Articles, foreign_key=post_id))]
+pub struct PostTags {
+    /// Field Representing column `post_id`
+    pub post_id: i64,
+    /// Field Representing column `tag`
+    pub tag: String,
+}
+
+/// Create struct for [`PostTags`] on table `post_tags`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable)]
+#[diesel(table_name=post_tags)]
+pub struct CreatePostTags {
+    /// Field Representing column `post_id`
+    pub post_id: i64,
+    /// Field Representing column `tag`
+    pub tag: String,
+}
+
+/// Column to order [`PostTags`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PostTagsOrderBy {
+    /// Order by column `post_id`
+    PostId(OrderDirection),
+    /// Order by column `tag`
+    Tag(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl PostTags {
+
+    /// Insert a new row on post_tags with a given [`CreatePostTags`]
+    pub fn create(db: &mut Connection, item: &CreatePostTags) -> QueryResult<Self> {
+        use crate::schema::post_tags::dsl::*;
+
+        insert_into(post_tags).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_post_id: i64, param_tag: String) -> QueryResult<Self> {
+        use crate::schema::post_tags::dsl::*;
+
+        post_tags.filter(post_id.eq(param_post_id)).filter(tag.eq(param_tag)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::post_tags::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = post_tags.count().get_result(db)?;
+        let items = post_tags.order_by((post_id, tag)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[PostTagsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::post_tags::table.count().get_result(db)?;
+
+        let mut query = crate::schema::post_tags::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                PostTagsOrderBy::PostId(OrderDirection::Asc) => query.then_order_by(crate::schema::post_tags::post_id.asc()),
+                PostTagsOrderBy::PostId(OrderDirection::Desc) => query.then_order_by(crate::schema::post_tags::post_id.desc()),
+                PostTagsOrderBy::Tag(OrderDirection::Asc) => query.then_order_by(crate::schema::post_tags::tag.asc()),
+                PostTagsOrderBy::Tag(OrderDirection::Desc) => query.then_order_by(crate::schema::post_tags::tag.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::post_tags::post_id.asc()).then_order_by(crate::schema::post_tags::tag.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `post_id`
+    pub fn filter_by_post_id(db: &mut Connection, param_post_id: i64) -> QueryResult<Vec<Self>> {
+        use crate::schema::post_tags::dsl::*;
+
+        post_tags.filter(post_id.eq(&param_post_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `post_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_post_id(db: &mut Connection, param_post_id: i64, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::post_tags::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = post_tags.filter(post_id.eq(&param_post_id)).count().get_result(db)?;
+        let items = post_tags.filter(post_id.eq(&param_post_id)).order_by((post_id, tag)).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_post_id: i64, param_tag: String) -> QueryResult<usize> {
+        use crate::schema::post_tags::dsl::*;
+
+        diesel::delete(post_tags.filter(post_id.eq(param_post_id)).filter(tag.eq(param_tag))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Articles`]
+    pub fn for_post(db: &mut Connection, parent: &Articles) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Articles`] this row belongs to with column `post_id`
+    pub fn post(&self, db: &mut Connection) -> QueryResult<Articles> {
+        let fk = &self.post_id;
+
+        crate::schema::articles::table.find(fk).select(Articles::as_select()).first::<Articles>(db)
+    }
+
+}
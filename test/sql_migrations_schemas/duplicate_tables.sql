@@ -0,0 +1,7 @@
+CREATE TABLE a.users (
+    id INTEGER PRIMARY KEY
+);
+
+CREATE TABLE b.users (
+    id INTEGER PRIMARY KEY
+);
@@ -0,0 +1,167 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::MysqlConnection;
+
+/// Struct representing a row for table `settings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=settings, primary_key(id))]
+pub struct Settings {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `enabled`
+    pub enabled: i8,
+    /// Field Representing column `priority`
+    pub priority: Option<i8>,
+}
+
+/// Create struct for [`Settings`] on table `settings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=settings)]
+pub struct CreateSettings {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `enabled`
+    pub enabled: i8,
+    /// Field Representing column `priority`
+    pub priority: Option<i8>,
+}
+
+/// Update struct for [`Settings`] on table `settings`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=settings)]
+pub struct UpdateSettings {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+    /// Field Representing column `enabled`
+    pub enabled: Option<i8>,
+    /// Field Representing column `priority`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub priority: Option<Option<i8>>,
+}
+
+/// Column to order [`Settings`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SettingsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+    /// Order by column `enabled`
+    Enabled(OrderDirection),
+    /// Order by column `priority`
+    Priority(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Settings {
+
+    /// Insert a new row on settings with a given [`CreateSettings`]
+    pub fn create(db: &mut Connection, item: &CreateSettings) -> QueryResult<Self> {
+        use crate::schema::settings::dsl::*;
+
+        insert_into(settings).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::settings::dsl::*;
+
+        settings.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::settings::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = settings.count().get_result(db)?;
+        let items = settings.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[SettingsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::settings::table.count().get_result(db)?;
+
+        let mut query = crate::schema::settings::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                SettingsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::settings::id.asc()),
+                SettingsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::settings::id.desc()),
+                SettingsOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::settings::name.asc()),
+                SettingsOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::settings::name.desc()),
+                SettingsOrderBy::Enabled(OrderDirection::Asc) => query.then_order_by(crate::schema::settings::enabled.asc()),
+                SettingsOrderBy::Enabled(OrderDirection::Desc) => query.then_order_by(crate::schema::settings::enabled.desc()),
+                SettingsOrderBy::Priority(OrderDirection::Asc) => query.then_order_by(crate::schema::settings::priority.asc()),
+                SettingsOrderBy::Priority(OrderDirection::Desc) => query.then_order_by(crate::schema::settings::priority.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::settings::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateSettings`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateSettings) -> QueryResult<Self> {
+        use crate::schema::settings::dsl::*;
+
+        diesel::update(settings.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::settings::dsl::*;
+
+        diesel::delete(settings.filter(id.eq(param_id))).execute(db)
+    }
+
+}
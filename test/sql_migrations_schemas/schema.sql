@@ -0,0 +1,11 @@
+CREATE TABLE app.settings (
+    id INTEGER PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    enabled TINYINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS app.settings (
+    id INTEGER PRIMARY KEY
+);
+
+ALTER TABLE app.settings ADD COLUMN priority TINYINT;
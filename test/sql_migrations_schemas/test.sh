@@ -0,0 +1,13 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+cargo run -- -i schema.sql -o models -c "diesel::MysqlConnection"
+
+# tables with the same name in different schemas cannot be told apart, so this has to fail
+if cargo run -- -i duplicate_tables.sql -o models -c "diesel::MysqlConnection" --check; then
+    echo "Expected tables with the same name in different schemas to be a error"
+    exit 1
+fi
@@ -0,0 +1,143 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `audit_logs`
+#[derive(Debug, Clone, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=audit_logs, primary_key(id))]
+pub struct AuditLogs {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `action`
+    pub action: crate::models::types::AuditAction,
+}
+
+/// Create struct for [`AuditLogs`] on table `audit_logs`
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=audit_logs)]
+pub struct CreateAuditLogs {
+    /// Field Representing column `action`
+    pub action: crate::models::types::AuditAction,
+}
+
+/// Update struct for [`AuditLogs`] on table `audit_logs`
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=audit_logs)]
+pub struct UpdateAuditLogs {
+    /// Field Representing column `action`
+    pub action: Option<crate::models::types::AuditAction>,
+}
+
+/// Column to order [`AuditLogs`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditLogsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `action`
+    Action(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, )]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl AuditLogs {
+
+    /// Insert a new row on audit_logs with a given [`CreateAuditLogs`]
+    pub fn create(db: &mut Connection, item: &CreateAuditLogs) -> QueryResult<Self> {
+        use crate::schema::audit_logs::dsl::*;
+
+        insert_into(audit_logs).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::audit_logs::dsl::*;
+
+        audit_logs.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::audit_logs::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = audit_logs.count().get_result(db)?;
+        let items = audit_logs.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[AuditLogsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::audit_logs::table.count().get_result(db)?;
+
+        let mut query = crate::schema::audit_logs::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                AuditLogsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::audit_logs::id.asc()),
+                AuditLogsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::audit_logs::id.desc()),
+                AuditLogsOrderBy::Action(OrderDirection::Asc) => query.then_order_by(crate::schema::audit_logs::action.asc()),
+                AuditLogsOrderBy::Action(OrderDirection::Desc) => query.then_order_by(crate::schema::audit_logs::action.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::audit_logs::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateAuditLogs`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateAuditLogs) -> QueryResult<Self> {
+        use crate::schema::audit_logs::dsl::*;
+
+        diesel::update(audit_logs.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::audit_logs::dsl::*;
+
+        diesel::delete(audit_logs.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,75 @@
+/* This file is generated and managed by dsync */
+
+use serde::{Deserialize, Serialize};
+
+/// Rust type for sql type [`crate::schema::sql_types::Mood`] (`mood`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::Mood)]
+pub enum Mood {
+    /// Variant representing `happy`
+    #[serde(rename = "happy")]
+    Happy,
+    /// Variant representing `sad`
+    #[serde(rename = "sad")]
+    Sad,
+}
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::Mood, diesel::pg::Pg> for Mood {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"happy" => Ok(Self::Happy),
+            b"sad" => Ok(Self::Sad),
+            v => Err(format!("Unknown variant for \"Mood\": \"{}\"", String::from_utf8_lossy(v)).into()),
+        }
+    }
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::Mood, diesel::pg::Pg> for Mood {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        let value = match self {
+            Self::Happy => "happy",
+            Self::Sad => "sad",
+        };
+
+        out.write_all(value.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+/// Rust type for sql type [`crate::schema::sql_types::AuditAction`] (`audit_action`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::AuditAction)]
+pub enum AuditAction {
+    /// Variant representing `insert`
+    Insert,
+    /// Variant representing `delete`
+    Delete,
+}
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::AuditAction, diesel::pg::Pg> for AuditAction {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"insert" => Ok(Self::Insert),
+            b"delete" => Ok(Self::Delete),
+            v => Err(format!("Unknown variant for \"AuditAction\": \"{}\"", String::from_utf8_lossy(v)).into()),
+        }
+    }
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::AuditAction, diesel::pg::Pg> for AuditAction {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        let value = match self {
+            Self::Insert => "insert",
+            Self::Delete => "delete",
+        };
+
+        out.write_all(value.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
@@ -0,0 +1,84 @@
+/* This file is generated and managed by dsync */
+
+use serde::{Deserialize, Serialize};
+
+/// Rust type for sql type [`crate::schema::sql_types::Mood`] (`mood`)
+#[tsync::tsync]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::Mood)]
+pub enum Mood {
+    /// Variant representing `happy`
+    #[serde(rename = "happy")]
+    Happy,
+    /// Variant representing `sad`
+    #[serde(rename = "sad")]
+    Sad,
+    /// Variant representing `very_happy`
+    #[serde(rename = "very_happy")]
+    VeryHappy,
+}
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::Mood, diesel::pg::Pg> for Mood {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"happy" => Ok(Self::Happy),
+            b"sad" => Ok(Self::Sad),
+            b"very_happy" => Ok(Self::VeryHappy),
+            v => Err(format!("Unknown variant for \"Mood\": \"{}\"", String::from_utf8_lossy(v)).into()),
+        }
+    }
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::Mood, diesel::pg::Pg> for Mood {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        let value = match self {
+            Self::Happy => "happy",
+            Self::Sad => "sad",
+            Self::VeryHappy => "very_happy",
+        };
+
+        out.write_all(value.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+/// Rust type for sql type [`crate::schema::sql_types::UserRole`] (`user_role`)
+#[tsync::tsync]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::UserRole)]
+pub enum UserRole {
+    /// Variant representing `admin`
+    #[serde(rename = "admin")]
+    Admin,
+    /// Variant representing `user`
+    #[serde(rename = "user")]
+    User,
+}
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::UserRole, diesel::pg::Pg> for UserRole {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"admin" => Ok(Self::Admin),
+            b"user" => Ok(Self::User),
+            v => Err(format!("Unknown variant for \"UserRole\": \"{}\"", String::from_utf8_lossy(v)).into()),
+        }
+    }
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::UserRole, diesel::pg::Pg> for UserRole {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        let value = match self {
+            Self::Admin => "admin",
+            Self::User => "user",
+        };
+
+        out.write_all(value.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
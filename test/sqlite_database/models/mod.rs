@@ -0,0 +1,3 @@
+pub mod post_tags;
+pub mod posts;
+pub mod users;
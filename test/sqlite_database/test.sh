@@ -0,0 +1,12 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+# the database is created from "schema.sql" in a temporary directory, so that no binary file needs to be committed
+DATABASE_DIR="$(mktemp -d)"
+trap 'rm -rf "$DATABASE_DIR"' EXIT
+sqlite3 "$DATABASE_DIR/database.sqlite" < schema.sql
+
+cargo run --features sqlite -- -i "$DATABASE_DIR/database.sqlite" -o models -c "diesel::SqliteConnection"
\ No newline at end of file
@@ -0,0 +1,153 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::user_addresses::UserAddressRow;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `categories`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, AsChangeset)]
+#[diesel(table_name=categories, primary_key(id))]
+pub struct CategoryRow {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+}
+
+/// Create struct for [`CategoryRow`] on table `categories`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=categories)]
+pub struct NewCategory {
+    /// Field Representing column `name`
+    pub name: String,
+}
+
+/// Update struct for [`CategoryRow`] on table `categories`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=categories)]
+pub struct CategoryChangeset {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+}
+
+/// Column to order [`CategoryRow`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CategoryRowOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl CategoryRow {
+
+    /// Insert a new row on categories with a given [`NewCategory`]
+    pub fn create(db: &mut Connection, item: &NewCategory) -> QueryResult<Self> {
+        use crate::schema::categories::dsl::*;
+
+        insert_into(categories).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::categories::dsl::*;
+
+        categories.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::categories::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = categories.count().get_result(db)?;
+        let items = categories.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[CategoryRowOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::categories::table.count().get_result(db)?;
+
+        let mut query = crate::schema::categories::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                CategoryRowOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::categories::id.asc()),
+                CategoryRowOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::categories::id.desc()),
+                CategoryRowOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::categories::name.asc()),
+                CategoryRowOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::categories::name.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::categories::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`CategoryChangeset`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &CategoryChangeset) -> QueryResult<Self> {
+        use crate::schema::categories::dsl::*;
+
+        diesel::update(categories.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::categories::dsl::*;
+
+        diesel::delete(categories.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Load all rows of table `user_addresses` which belong to the given rows, grouped by the given rows
+    pub fn load_user_addresses(db: &mut Connection, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<UserAddressRow>)>> {
+        let children = UserAddressRow::belonging_to(parents).select(UserAddressRow::as_select()).load::<UserAddressRow>(db)?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }
+
+}
@@ -0,0 +1,144 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `criteria`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=criteria, primary_key(id))]
+pub struct CriterionRow {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `description`
+    pub description: String,
+}
+
+/// Create struct for [`CriterionRow`] on table `criteria`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=criteria)]
+pub struct NewCriterion {
+    /// Field Representing column `description`
+    pub description: String,
+}
+
+/// Update struct for [`CriterionRow`] on table `criteria`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=criteria)]
+pub struct CriterionChangeset {
+    /// Field Representing column `description`
+    pub description: Option<String>,
+}
+
+/// Column to order [`CriterionRow`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CriterionRowOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `description`
+    Description(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl CriterionRow {
+
+    /// Insert a new row on criteria with a given [`NewCriterion`]
+    pub fn create(db: &mut Connection, item: &NewCriterion) -> QueryResult<Self> {
+        use crate::schema::criteria::dsl::*;
+
+        insert_into(criteria).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::criteria::dsl::*;
+
+        criteria.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::criteria::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = criteria.count().get_result(db)?;
+        let items = criteria.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[CriterionRowOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::criteria::table.count().get_result(db)?;
+
+        let mut query = crate::schema::criteria::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                CriterionRowOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::criteria::id.asc()),
+                CriterionRowOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::criteria::id.desc()),
+                CriterionRowOrderBy::Description(OrderDirection::Asc) => query.then_order_by(crate::schema::criteria::description.asc()),
+                CriterionRowOrderBy::Description(OrderDirection::Desc) => query.then_order_by(crate::schema::criteria::description.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::criteria::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`CriterionChangeset`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &CriterionChangeset) -> QueryResult<Self> {
+        use crate::schema::criteria::dsl::*;
+
+        diesel::update(criteria.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::criteria::dsl::*;
+
+        diesel::delete(criteria.filter(id.eq(param_id))).execute(db)
+    }
+
+}
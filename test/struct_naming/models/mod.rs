@@ -0,0 +1,4 @@
+pub mod categories;
+pub mod criteria;
+pub mod people;
+pub mod user_addresses;
@@ -0,0 +1,153 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::user_addresses::UserAddressRow;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `people`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, AsChangeset)]
+#[diesel(table_name=people, primary_key(id))]
+pub struct MemberRow {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `name`
+    pub name: String,
+}
+
+/// Create struct for [`MemberRow`] on table `people`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=people)]
+pub struct NewMember {
+    /// Field Representing column `name`
+    pub name: String,
+}
+
+/// Update struct for [`MemberRow`] on table `people`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=people)]
+pub struct MemberChangeset {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+}
+
+/// Column to order [`MemberRow`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemberRowOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl MemberRow {
+
+    /// Insert a new row on people with a given [`NewMember`]
+    pub fn create(db: &mut Connection, item: &NewMember) -> QueryResult<Self> {
+        use crate::schema::people::dsl::*;
+
+        insert_into(people).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::people::dsl::*;
+
+        people.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::people::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = people.count().get_result(db)?;
+        let items = people.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[MemberRowOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::people::table.count().get_result(db)?;
+
+        let mut query = crate::schema::people::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                MemberRowOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::people::id.asc()),
+                MemberRowOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::people::id.desc()),
+                MemberRowOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::people::name.asc()),
+                MemberRowOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::people::name.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::people::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`MemberChangeset`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &MemberChangeset) -> QueryResult<Self> {
+        use crate::schema::people::dsl::*;
+
+        diesel::update(people.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::people::dsl::*;
+
+        diesel::delete(people.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Load all rows of table `user_addresses` which belong to the given rows, grouped by the given rows
+    pub fn load_user_addresses(db: &mut Connection, parents: &[Self]) -> QueryResult<Vec<(Self, Vec<UserAddressRow>)>> {
+        let children = UserAddressRow::belonging_to(parents).select(UserAddressRow::as_select()).load::<UserAddressRow>(db)?;
+        let grouped = children.grouped_by(parents);
+
+        Ok(parents.iter().cloned().zip(grouped).collect())
+    }
+
+}
@@ -0,0 +1,240 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::categories::CategoryRow;
+use crate::models::people::MemberRow;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `user_addresses`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations, AsChangeset)]
+#[diesel(table_name=user_addresses, primary_key(id), belongs_to(CategoryRow, foreign_key=category_id) , belongs_to(MemberRow, foreign_key=person_id))]
+pub struct UserAddressRow {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `person_id`
+    pub person_id: i32,
+    /// Field Representing column `category_id`
+    pub category_id: i32,
+    /// Field Representing column `street`
+    pub street: String,
+}
+
+/// Create struct for [`UserAddressRow`] on table `user_addresses`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=user_addresses)]
+pub struct NewUserAddress {
+    /// Field Representing column `person_id`
+    pub person_id: i32,
+    /// Field Representing column `category_id`
+    pub category_id: i32,
+    /// Field Representing column `street`
+    pub street: String,
+}
+
+/// Update struct for [`UserAddressRow`] on table `user_addresses`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=user_addresses)]
+pub struct UserAddressChangeset {
+    /// Field Representing column `person_id`
+    pub person_id: Option<i32>,
+    /// Field Representing column `category_id`
+    pub category_id: Option<i32>,
+    /// Field Representing column `street`
+    pub street: Option<String>,
+}
+
+/// Column to order [`UserAddressRow`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UserAddressRowOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `person_id`
+    PersonId(OrderDirection),
+    /// Order by column `category_id`
+    CategoryId(OrderDirection),
+    /// Order by column `street`
+    Street(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl UserAddressRow {
+
+    /// Insert a new row on user_addresses with a given [`NewUserAddress`]
+    pub fn create(db: &mut Connection, item: &NewUserAddress) -> QueryResult<Self> {
+        use crate::schema::user_addresses::dsl::*;
+
+        insert_into(user_addresses).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::user_addresses::dsl::*;
+
+        user_addresses.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::user_addresses::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = user_addresses.count().get_result(db)?;
+        let items = user_addresses.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[UserAddressRowOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::user_addresses::table.count().get_result(db)?;
+
+        let mut query = crate::schema::user_addresses::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                UserAddressRowOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::user_addresses::id.asc()),
+                UserAddressRowOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::user_addresses::id.desc()),
+                UserAddressRowOrderBy::PersonId(OrderDirection::Asc) => query.then_order_by(crate::schema::user_addresses::person_id.asc()),
+                UserAddressRowOrderBy::PersonId(OrderDirection::Desc) => query.then_order_by(crate::schema::user_addresses::person_id.desc()),
+                UserAddressRowOrderBy::CategoryId(OrderDirection::Asc) => query.then_order_by(crate::schema::user_addresses::category_id.asc()),
+                UserAddressRowOrderBy::CategoryId(OrderDirection::Desc) => query.then_order_by(crate::schema::user_addresses::category_id.desc()),
+                UserAddressRowOrderBy::Street(OrderDirection::Asc) => query.then_order_by(crate::schema::user_addresses::street.asc()),
+                UserAddressRowOrderBy::Street(OrderDirection::Desc) => query.then_order_by(crate::schema::user_addresses::street.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::user_addresses::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `person_id`
+    pub fn filter_by_person_id(db: &mut Connection, param_person_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::user_addresses::dsl::*;
+
+        user_addresses.filter(person_id.eq(&param_person_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `person_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_person_id(db: &mut Connection, param_person_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::user_addresses::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = user_addresses.filter(person_id.eq(&param_person_id)).count().get_result(db)?;
+        let items = user_addresses.filter(person_id.eq(&param_person_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `category_id`
+    pub fn filter_by_category_id(db: &mut Connection, param_category_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::user_addresses::dsl::*;
+
+        user_addresses.filter(category_id.eq(&param_category_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `category_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_category_id(db: &mut Connection, param_category_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::user_addresses::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = user_addresses.filter(category_id.eq(&param_category_id)).count().get_result(db)?;
+        let items = user_addresses.filter(category_id.eq(&param_category_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UserAddressChangeset`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UserAddressChangeset) -> QueryResult<Self> {
+        use crate::schema::user_addresses::dsl::*;
+
+        diesel::update(user_addresses.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::user_addresses::dsl::*;
+
+        diesel::delete(user_addresses.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`CategoryRow`]
+    pub fn for_category(db: &mut Connection, parent: &CategoryRow) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`CategoryRow`] this row belongs to with column `category_id`
+    pub fn category(&self, db: &mut Connection) -> QueryResult<CategoryRow> {
+        let fk = &self.category_id;
+
+        crate::schema::categories::table.find(fk).select(CategoryRow::as_select()).first::<CategoryRow>(db)
+    }
+
+    /// Get all rows which belong to the given [`MemberRow`]
+    pub fn for_person(db: &mut Connection, parent: &MemberRow) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`MemberRow`] this row belongs to with column `person_id`
+    pub fn person(&self, db: &mut Connection) -> QueryResult<MemberRow> {
+        let fk = &self.person_id;
+
+        crate::schema::people::table.find(fk).select(MemberRow::as_select()).first::<MemberRow>(db)
+    }
+
+}
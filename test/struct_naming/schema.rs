@@ -0,0 +1,39 @@
+diesel::table! {
+    categories (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    criteria (id) {
+        id -> Int4,
+        description -> Text,
+    }
+}
+
+diesel::table! {
+    people (id) {
+        id -> Int4,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    user_addresses (id) {
+        id -> Int4,
+        person_id -> Int4,
+        category_id -> Int4,
+        street -> Text,
+    }
+}
+
+diesel::joinable!(user_addresses -> categories (category_id));
+diesel::joinable!(user_addresses -> people (person_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    categories,
+    criteria,
+    people,
+    user_addresses,
+);
@@ -0,0 +1,215 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `users`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=users, primary_key(id))]
+pub struct Users {
+    /// Field Representing column `id`
+    pub id: crate::types::UserId,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `balance`
+    pub balance: rust_decimal::Decimal,
+    /// Field Representing column `previous_balances`
+    pub previous_balances: Vec<rust_decimal::Decimal>,
+    /// Field Representing column `balance_limits`
+    pub balance_limits: (std::ops::Bound<rust_decimal::Decimal>, std::ops::Bound<rust_decimal::Decimal>),
+    /// Field Representing column `last_login`
+    pub last_login: Option<time::PrimitiveDateTime>,
+    /// Field Representing column `active_period`
+    pub active_period: Option<(std::ops::Bound<time::PrimitiveDateTime>, std::ops::Bound<time::PrimitiveDateTime>)>,
+    /// Field Representing column `settings`
+    pub settings: Option<crate::types::UserSettings>,
+    /// Field Representing column `metadata`
+    pub metadata: serde_json::Value,
+    /// Field Representing column `created_at`
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// Create struct for [`Users`] on table `users`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=users)]
+pub struct CreateUsers {
+    /// Field Representing column `id`
+    pub id: crate::types::UserId,
+    /// Field Representing column `name`
+    pub name: String,
+    /// Field Representing column `balance`
+    pub balance: rust_decimal::Decimal,
+    /// Field Representing column `previous_balances`
+    pub previous_balances: Vec<rust_decimal::Decimal>,
+    /// Field Representing column `balance_limits`
+    pub balance_limits: (std::ops::Bound<rust_decimal::Decimal>, std::ops::Bound<rust_decimal::Decimal>),
+    /// Field Representing column `last_login`
+    pub last_login: Option<time::PrimitiveDateTime>,
+    /// Field Representing column `active_period`
+    pub active_period: Option<(std::ops::Bound<time::PrimitiveDateTime>, std::ops::Bound<time::PrimitiveDateTime>)>,
+    /// Field Representing column `settings`
+    pub settings: Option<crate::types::UserSettings>,
+    /// Field Representing column `metadata`
+    pub metadata: serde_json::Value,
+}
+
+/// Update struct for [`Users`] on table `users`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=users)]
+pub struct UpdateUsers {
+    /// Field Representing column `name`
+    pub name: Option<String>,
+    /// Field Representing column `balance`
+    pub balance: Option<rust_decimal::Decimal>,
+    /// Field Representing column `previous_balances`
+    pub previous_balances: Option<Vec<rust_decimal::Decimal>>,
+    /// Field Representing column `balance_limits`
+    pub balance_limits: Option<(std::ops::Bound<rust_decimal::Decimal>, std::ops::Bound<rust_decimal::Decimal>)>,
+    /// Field Representing column `last_login`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub last_login: Option<Option<time::PrimitiveDateTime>>,
+    /// Field Representing column `active_period`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub active_period: Option<Option<(std::ops::Bound<time::PrimitiveDateTime>, std::ops::Bound<time::PrimitiveDateTime>)>>,
+    /// Field Representing column `settings`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub settings: Option<Option<crate::types::UserSettings>>,
+    /// Field Representing column `metadata`
+    pub metadata: Option<serde_json::Value>,
+    /// Field Representing column `created_at`
+    pub created_at: Option<time::PrimitiveDateTime>,
+}
+
+/// Column to order [`Users`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UsersOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `name`
+    Name(OrderDirection),
+    /// Order by column `balance`
+    Balance(OrderDirection),
+    /// Order by column `balance_limits`
+    BalanceLimits(OrderDirection),
+    /// Order by column `last_login`
+    LastLogin(OrderDirection),
+    /// Order by column `active_period`
+    ActivePeriod(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Users {
+
+    /// Insert a new row on users with a given [`CreateUsers`]
+    pub fn create(db: &mut Connection, item: &CreateUsers) -> QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        insert_into(users).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: crate::types::UserId) -> QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        users.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::users::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = users.count().get_result(db)?;
+        let items = users.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[UsersOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::users::table.count().get_result(db)?;
+
+        let mut query = crate::schema::users::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                UsersOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::users::id.asc()),
+                UsersOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::users::id.desc()),
+                UsersOrderBy::Name(OrderDirection::Asc) => query.then_order_by(crate::schema::users::name.asc()),
+                UsersOrderBy::Name(OrderDirection::Desc) => query.then_order_by(crate::schema::users::name.desc()),
+                UsersOrderBy::Balance(OrderDirection::Asc) => query.then_order_by(crate::schema::users::balance.asc()),
+                UsersOrderBy::Balance(OrderDirection::Desc) => query.then_order_by(crate::schema::users::balance.desc()),
+                UsersOrderBy::BalanceLimits(OrderDirection::Asc) => query.then_order_by(crate::schema::users::balance_limits.asc()),
+                UsersOrderBy::BalanceLimits(OrderDirection::Desc) => query.then_order_by(crate::schema::users::balance_limits.desc()),
+                UsersOrderBy::LastLogin(OrderDirection::Asc) => query.then_order_by(crate::schema::users::last_login.asc()),
+                UsersOrderBy::LastLogin(OrderDirection::Desc) => query.then_order_by(crate::schema::users::last_login.desc()),
+                UsersOrderBy::ActivePeriod(OrderDirection::Asc) => query.then_order_by(crate::schema::users::active_period.asc()),
+                UsersOrderBy::ActivePeriod(OrderDirection::Desc) => query.then_order_by(crate::schema::users::active_period.desc()),
+                UsersOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::users::created_at.asc()),
+                UsersOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::users::created_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::users::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateUsers`]
+    pub fn update(db: &mut Connection, param_id: crate::types::UserId, item: &UpdateUsers) -> QueryResult<Self> {
+        use crate::schema::users::dsl::*;
+
+        diesel::update(users.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: crate::types::UserId) -> QueryResult<usize> {
+        use crate::schema::users::dsl::*;
+
+        diesel::delete(users.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,14 @@
+diesel::table! {
+    users (id) {
+        id -> Uuid,
+        name -> Text,
+        balance -> Numeric,
+        previous_balances -> Array<Numeric>,
+        balance_limits -> Numrange,
+        last_login -> Nullable<Timestamp>,
+        active_period -> Nullable<Tsrange>,
+        settings -> Nullable<Jsonb>,
+        metadata -> Json,
+        created_at -> Timestamp,
+    }
+}
@@ -0,0 +1,7 @@
+#!/bin/bash
+
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )"
+
+cd $SCRIPT_DIR
+
+cargo run -- -i schema.rs -o models -g created_at --sql-type-override "Numeric=rust_decimal::Decimal" --sql-type-override "timestamp=time::PrimitiveDateTime" --column-type-override "users.id=crate::types::UserId" --column-type-override "users.settings=crate::types::UserSettings" -c "diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>"
\ No newline at end of file
@@ -0,0 +1,155 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::PgConnection;
+
+/// Struct representing a row for table `profiles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, AsChangeset)]
+#[diesel(table_name=profiles, primary_key(id))]
+pub struct Profiles {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `display_name`
+    pub display_name: String,
+    /// Field Representing column `bio`
+    pub bio: Option<String>,
+}
+
+/// Create struct for [`Profiles`] on table `profiles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=profiles)]
+pub struct CreateProfiles {
+    /// Field Representing column `display_name`
+    pub display_name: String,
+    /// Field Representing column `bio`
+    pub bio: Option<String>,
+}
+
+/// Update struct for [`Profiles`] on table `profiles`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=profiles)]
+pub struct UpdateProfiles {
+    /// Field Representing column `display_name`
+    pub display_name: Option<String>,
+    /// Field Representing column `bio`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
+    pub bio: Option<Option<String>>,
+}
+
+/// Column to order [`Profiles`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProfilesOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `display_name`
+    DisplayName(OrderDirection),
+    /// Order by column `bio`
+    Bio(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Profiles {
+
+    /// Insert a new row on profiles with a given [`CreateProfiles`]
+    pub fn create(db: &mut Connection, item: &CreateProfiles) -> QueryResult<Self> {
+        use crate::schema::profiles::dsl::*;
+
+        insert_into(profiles).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::profiles::dsl::*;
+
+        profiles.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::profiles::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = profiles.count().get_result(db)?;
+        let items = profiles.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[ProfilesOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::profiles::table.count().get_result(db)?;
+
+        let mut query = crate::schema::profiles::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                ProfilesOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::profiles::id.asc()),
+                ProfilesOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::profiles::id.desc()),
+                ProfilesOrderBy::DisplayName(OrderDirection::Asc) => query.then_order_by(crate::schema::profiles::display_name.asc()),
+                ProfilesOrderBy::DisplayName(OrderDirection::Desc) => query.then_order_by(crate::schema::profiles::display_name.desc()),
+                ProfilesOrderBy::Bio(OrderDirection::Asc) => query.then_order_by(crate::schema::profiles::bio.asc()),
+                ProfilesOrderBy::Bio(OrderDirection::Desc) => query.then_order_by(crate::schema::profiles::bio.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::profiles::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateProfiles`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateProfiles) -> QueryResult<Self> {
+        use crate::schema::profiles::dsl::*;
+
+        diesel::update(profiles.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::profiles::dsl::*;
+
+        diesel::delete(profiles.filter(id.eq(param_id))).execute(db)
+    }
+
+}
@@ -0,0 +1,15 @@
+diesel::table! {
+    profiles (id) {
+        id -> Int4,
+        display_name -> Text,
+        bio -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    settings (id) {
+        id -> Int4,
+        theme -> Text,
+        locale -> Nullable<Text>,
+    }
+}
@@ -1,4 +1,5 @@
 /* This file is generated and managed by dsync */
+/* Date and time types require diesel feature "chrono" */
 
 use crate::diesel::*;
 use serde::{Deserialize, Serialize};
@@ -18,7 +19,7 @@ pub struct FangTasks {
     /// Field Representing column `error_message`
     pub error_message: Option<String>,
     /// Field Representing column `state`
-    pub state: crate::schema::sql_types::FangTaskState,
+    pub state: crate::models::types::FangTaskState,
     /// Field Representing column `task_type`
     pub task_type: String,
     /// Field Representing column `uniq_hash`
@@ -44,7 +45,7 @@ pub struct CreateFangTasks {
     /// Field Representing column `error_message`
     pub error_message: Option<String>,
     /// Field Representing column `state`
-    pub state: crate::schema::sql_types::FangTaskState,
+    pub state: crate::models::types::FangTaskState,
     /// Field Representing column `task_type`
     pub task_type: String,
     /// Field Representing column `uniq_hash`
@@ -66,12 +67,14 @@ pub struct UpdateFangTasks {
     /// Field Representing column `metadata`
     pub metadata: Option<serde_json::Value>,
     /// Field Representing column `error_message`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
     pub error_message: Option<Option<String>>,
     /// Field Representing column `state`
-    pub state: Option<crate::schema::sql_types::FangTaskState>,
+    pub state: Option<crate::models::types::FangTaskState>,
     /// Field Representing column `task_type`
     pub task_type: Option<String>,
     /// Field Representing column `uniq_hash`
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "::serde_with::rust::double_option")]
     pub uniq_hash: Option<Option<String>>,
     /// Field Representing column `retries`
     pub retries: Option<i32>,
@@ -83,6 +86,29 @@ pub struct UpdateFangTasks {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Column to order [`FangTasks`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FangTasksOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `error_message`
+    ErrorMessage(OrderDirection),
+    /// Order by column `state`
+    State(OrderDirection),
+    /// Order by column `task_type`
+    TaskType(OrderDirection),
+    /// Order by column `uniq_hash`
+    UniqHash(OrderDirection),
+    /// Order by column `retries`
+    Retries(OrderDirection),
+    /// Order by column `scheduled_at`
+    ScheduledAt(OrderDirection),
+    /// Order by column `created_at`
+    CreatedAt(OrderDirection),
+    /// Order by column `updated_at`
+    UpdatedAt(OrderDirection),
+}
+
 /// Result of a `.paginate` function
 #[derive(Debug, Serialize)]
 pub struct PaginationResult<T> {
@@ -98,6 +124,15 @@ pub struct PaginationResult<T> {
     pub num_pages: i64,
 }
 
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
 impl FangTasks {
 
     /// Insert a new row on fang_tasks with a given [`CreateFangTasks`]
@@ -114,13 +149,54 @@ impl FangTasks {
         fang_tasks.filter(id.eq(param_id)).first::<Self>(db)
     }
 
-    /// Paginates through the table where page is a 0-based index (i.e. page 0 is the first page)
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
     pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
         use crate::schema::fang_tasks::dsl::*;
 
         let page_size = if page_size < 1 { 1 } else { page_size };
         let total_items = fang_tasks.count().get_result(db)?;
-        let items = fang_tasks.limit(page_size).offset(page * page_size).load::<Self>(db)?;
+        let items = fang_tasks.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[FangTasksOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::fang_tasks::table.count().get_result(db)?;
+
+        let mut query = crate::schema::fang_tasks::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                FangTasksOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::id.asc()),
+                FangTasksOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::id.desc()),
+                FangTasksOrderBy::ErrorMessage(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::error_message.asc()),
+                FangTasksOrderBy::ErrorMessage(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::error_message.desc()),
+                FangTasksOrderBy::State(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::state.asc()),
+                FangTasksOrderBy::State(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::state.desc()),
+                FangTasksOrderBy::TaskType(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::task_type.asc()),
+                FangTasksOrderBy::TaskType(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::task_type.desc()),
+                FangTasksOrderBy::UniqHash(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::uniq_hash.asc()),
+                FangTasksOrderBy::UniqHash(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::uniq_hash.desc()),
+                FangTasksOrderBy::Retries(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::retries.asc()),
+                FangTasksOrderBy::Retries(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::retries.desc()),
+                FangTasksOrderBy::ScheduledAt(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::scheduled_at.asc()),
+                FangTasksOrderBy::ScheduledAt(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::scheduled_at.desc()),
+                FangTasksOrderBy::CreatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::created_at.asc()),
+                FangTasksOrderBy::CreatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::created_at.desc()),
+                FangTasksOrderBy::UpdatedAt(OrderDirection::Asc) => query.then_order_by(crate::schema::fang_tasks::updated_at.asc()),
+                FangTasksOrderBy::UpdatedAt(OrderDirection::Desc) => query.then_order_by(crate::schema::fang_tasks::updated_at.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::fang_tasks::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
 
         Ok(PaginationResult {
             items,
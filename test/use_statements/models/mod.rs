@@ -1 +1,2 @@
 pub mod fang_tasks;
+pub mod types;
@@ -0,0 +1,24 @@
+/* This file is generated and managed by dsync */
+
+use serde::{Deserialize, Serialize};
+
+/// Rust type for sql type [`crate::schema::sql_types::FangTaskState`] (`fang_task_state`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = crate::schema::sql_types::FangTaskState)]
+pub struct FangTaskState(pub String);
+
+impl diesel::deserialize::FromSql<crate::schema::sql_types::FangTaskState, diesel::pg::Pg> for FangTaskState {
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(Self(String::from_utf8(bytes.as_bytes().to_vec())?))
+    }
+}
+
+impl diesel::serialize::ToSql<crate::schema::sql_types::FangTaskState, diesel::pg::Pg> for FangTaskState {
+    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        out.write_all(self.0.as_bytes())?;
+
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
@@ -0,0 +1,192 @@
+/* This file is generated and managed by dsync */
+
+use crate::diesel::*;
+use crate::models::todos::Todos;
+use serde::{Deserialize, Serialize};
+use crate::schema::*;
+
+/// Connection Type as set in dsync
+type Connection = diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<diesel::PgConnection>>;
+
+/// Struct representing a row for table `comments`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, Selectable, Identifiable, Associations, AsChangeset)]
+#[diesel(table_name=comments, primary_key(id), belongs_to(Todos, foreign_key=todo_id))]
+pub struct Comments {
+    /// Field Representing column `id`
+    pub id: i32,
+    /// Field Representing column `todo_id`
+    pub todo_id: i32,
+    /// Field Representing column `text`
+    pub text: String,
+}
+
+/// Create struct for [`Comments`] on table `comments`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=comments)]
+pub struct CreateComments {
+    /// Field Representing column `todo_id`
+    pub todo_id: i32,
+    /// Field Representing column `text`
+    pub text: String,
+}
+
+/// Update struct for [`Comments`] on table `comments`
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable, AsChangeset)]
+#[diesel(table_name=comments)]
+pub struct UpdateComments {
+    /// Field Representing column `todo_id`
+    pub todo_id: Option<i32>,
+    /// Field Representing column `text`
+    pub text: Option<String>,
+}
+
+/// Column to order [`Comments`] by in `paginate_ordered`, with the direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommentsOrderBy {
+    /// Order by column `id`
+    Id(OrderDirection),
+    /// Order by column `todo_id`
+    TodoId(OrderDirection),
+    /// Order by column `text`
+    Text(OrderDirection),
+}
+
+/// Result of a `.paginate` function
+#[derive(Debug, Serialize)]
+pub struct PaginationResult<T> {
+    /// Result items from the current page
+    pub items: Vec<T>,
+    /// Count of how many items there are in total
+    pub total_items: i64,
+    /// Current page, 0-based index
+    pub page: i64,
+    /// Size of a page
+    pub page_size: i64,
+    /// Number of pages in total
+    pub num_pages: i64,
+}
+
+/// Direction to order by, used by the `*OrderBy` enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderDirection {
+    /// Ascending, smallest value first
+    Asc,
+    /// Descending, largest value first
+    Desc,
+}
+
+impl Comments {
+
+    /// Insert a new row on comments with a given [`CreateComments`]
+    pub fn create(db: &mut Connection, item: &CreateComments) -> QueryResult<Self> {
+        use crate::schema::comments::dsl::*;
+
+        insert_into(comments).values(item).get_result::<Self>(db)
+    }
+
+    /// Get a specific row with the primary key
+    pub fn read(db: &mut Connection, param_id: i32) -> QueryResult<Self> {
+        use crate::schema::comments::dsl::*;
+
+        comments.filter(id.eq(param_id)).first::<Self>(db)
+    }
+
+    /// Paginates through the table ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate(db: &mut Connection, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::comments::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = comments.count().get_result(db)?;
+        let items = comments.order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Paginates through the table ordered by the given columns where page is a 0-based index (i.e. page 0 is the first page)
+    /// Rows which are equal in all given columns are ordered by the primary key, so that pages are stable
+    pub fn paginate_ordered(db: &mut Connection, page: i64, page_size: i64, order_by: &[CommentsOrderBy]) -> QueryResult<PaginationResult<Self>> {
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = crate::schema::comments::table.count().get_result(db)?;
+
+        let mut query = crate::schema::comments::table.into_boxed();
+        for order in order_by {
+            query = match order {
+                CommentsOrderBy::Id(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::id.asc()),
+                CommentsOrderBy::Id(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::id.desc()),
+                CommentsOrderBy::TodoId(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::todo_id.asc()),
+                CommentsOrderBy::TodoId(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::todo_id.desc()),
+                CommentsOrderBy::Text(OrderDirection::Asc) => query.then_order_by(crate::schema::comments::text.asc()),
+                CommentsOrderBy::Text(OrderDirection::Desc) => query.then_order_by(crate::schema::comments::text.desc()),
+            };
+        }
+        let items = query.then_order_by(crate::schema::comments::id.asc()).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Get all rows with the given value in column `todo_id`
+    pub fn filter_by_todo_id(db: &mut Connection, param_todo_id: i32) -> QueryResult<Vec<Self>> {
+        use crate::schema::comments::dsl::*;
+
+        comments.filter(todo_id.eq(&param_todo_id)).load::<Self>(db)
+    }
+
+    /// Paginates through the rows with the given value in column `todo_id` ordered by the primary key where page is a 0-based index (i.e. page 0 is the first page)
+    pub fn paginate_by_todo_id(db: &mut Connection, param_todo_id: i32, page: i64, page_size: i64) -> QueryResult<PaginationResult<Self>> {
+        use crate::schema::comments::dsl::*;
+
+        let page_size = if page_size < 1 { 1 } else { page_size };
+        let total_items = comments.filter(todo_id.eq(&param_todo_id)).count().get_result(db)?;
+        let items = comments.filter(todo_id.eq(&param_todo_id)).order_by(id).limit(page_size).offset(page * page_size).load::<Self>(db)?;
+
+        Ok(PaginationResult {
+            items,
+            total_items,
+            page,
+            page_size,
+            /* ceiling division of integers */
+            num_pages: total_items / page_size + i64::from(total_items % page_size != 0)
+        })
+    }
+
+    /// Update a row given the primary key with updates from [`UpdateComments`]
+    pub fn update(db: &mut Connection, param_id: i32, item: &UpdateComments) -> QueryResult<Self> {
+        use crate::schema::comments::dsl::*;
+
+        diesel::update(comments.filter(id.eq(param_id))).set(item).get_result(db)
+    }
+
+    /// Delete a row with the given primary key
+    pub fn delete(db: &mut Connection, param_id: i32) -> QueryResult<usize> {
+        use crate::schema::comments::dsl::*;
+
+        diesel::delete(comments.filter(id.eq(param_id))).execute(db)
+    }
+
+    /// Get all rows which belong to the given [`Todos`]
+    pub fn for_todo(db: &mut Connection, parent: &Todos) -> QueryResult<Vec<Self>> {
+        Self::belonging_to(parent).select(Self::as_select()).load::<Self>(db)
+    }
+
+    /// Get the [`Todos`] this row belongs to with column `todo_id`
+    pub fn todo(&self, db: &mut Connection) -> QueryResult<Todos> {
+        let fk = &self.todo_id;
+
+        crate::schema::todos::table.find(fk).select(Todos::as_select()).first::<Todos>(db)
+    }
+
+}
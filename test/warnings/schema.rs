@@ -0,0 +1,19 @@
+diesel::table! {
+    todos (id) {
+        id -> Int4,
+        text -> Text,
+        location -> Nullable<Geometry>,
+        tags -> Array<Nullable<Ltree>>,
+    }
+}
+
+diesel::table! {
+    comments (id) {
+        id -> Int4,
+        todo_id -> Int4,
+        text -> Text,
+    }
+}
+
+diesel::joinable!(comments -> todos (todo_id));
+diesel::joinable!(comments -> users (todo_id));